@@ -0,0 +1,31 @@
+//! Derive macro for [bevy_log_events](https://docs.rs/bevy_log_events), use it
+//! through the `derive` feature of this crate.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Registers the type so it is logged by `app.log_all_derived()`.
+///
+/// The type must also implement `Event` and `Debug`, generic types are not supported.
+#[proc_macro_derive(LoggedEvent)]
+pub fn derive_logged_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "LoggedEvent can not be derived for generic types",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let name = &input.ident;
+    quote! {
+        ::bevy_log_events::__private::inventory::submit! {
+            ::bevy_log_events::__private::DerivedEvent(
+                ::bevy_log_events::__private::register::<#name>
+            )
+        }
+    }
+    .into()
+}
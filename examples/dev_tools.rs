@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use bevy_log_events::{dev_tools::DevToolsAdapter, prelude::*};
+
+#[derive(Event, Debug)]
+struct Tick(#[allow(dead_code)] u32);
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            // The settings window is not needed to change the settings
+            LogEventsPlugin::new("assets/dev_tools.ron").add_egui_plugin(false),
+            // Bind commands to keys, they can also be run from your own tools with run_command
+            DevToolsAdapter::default()
+                .bind(KeyCode::F1, "toggle")
+                .bind(KeyCode::F2, "toggle dev_tools::Tick")
+                .bind(KeyCode::F3, "level dev_tools::Tick WARN")
+                .bind(KeyCode::F4, "level dev_tools::Tick INFO"),
+        ))
+        .add_and_log_event::<Tick>()
+        .add_systems(Update, send_tick.run_if(on_timer(Duration::from_secs(1))))
+        .run();
+}
+
+fn send_tick(mut events: EventWriter<Tick>, mut count: Local<u32>) {
+    *count += 1;
+    events.send(Tick(*count));
+}
@@ -20,13 +20,16 @@ pub(super) fn plugin(app: &mut App) {
     add_event::<B>(app);
     add_event::<C>(app);
     add_event::<D>(app);
-    app.add_plugins((bar::plugin, foo::plugin, baz::plugin, qux::plugin))
+    app.add_plugins((baz::plugin, qux::plugin))
+        // Events registered by these plugins will be grouped in their own channel
+        .log_channel("Bar", bar::plugin)
+        .log_channel("Foo", foo::plugin)
         .add_and_log_event::<TriggeredAndSent>()
         .log_triggered::<TriggeredAndSent>()
         .log_triggered::<Triggered>()
         .log_trigger::<OnAdd, MyComponent>()
         .log_trigger::<OnInsert, MyComponent>()
-        .log_trigger::<OnRemove, MyComponent>()
+        .log_trigger_cached::<OnRemove, MyComponent>()
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -91,7 +94,7 @@ struct TriggeredAndSent {
 #[derive(Component)]
 struct MyEntity;
 
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone)]
 struct MyComponent {
     #[allow(dead_code)]
     index: usize,
@@ -0,0 +1,35 @@
+//! Plays the [sounds](crate::LogEventsPluginSettings::sounds) of the logged events.
+
+use bevy::{
+    audio::{AudioPlugin, Volume},
+    prelude::*,
+};
+
+use crate::{systems::LogSettingsIds, LogInstance, LOG_TARGET};
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    if !app.is_plugin_added::<AudioPlugin>() {
+        warn!(target: LOG_TARGET, "There is no AudioPlugin, the sounds of the events will not be played. If you use one, add it before the LogEventsPlugin.");
+        return;
+    }
+    app.add_systems(Last, play_sounds::<I>);
+}
+
+fn play_sounds<I: LogInstance>(
+    mut commands: Commands,
+    log_settings_ids: Res<LogSettingsIds<I>>,
+    asset_server: Res<AssetServer>,
+) {
+    for registered in log_settings_ids.values() {
+        let Some(sound) = registered.stats.lock().pending_sound.take() else {
+            continue;
+        };
+        if sound.path.is_empty() {
+            continue;
+        }
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(sound.path)),
+            PlaybackSettings::DESPAWN.with_volume(Volume::new(sound.volume.max(0.0))),
+        ));
+    }
+}
@@ -0,0 +1,157 @@
+//! A command line tool to read and edit the settings files of `bevy_log_events`, so the
+//! committed ones can be maintained and checked in CI.
+//!
+//! Run it with `cargo run --features cli --bin log-settings -- <command>`.
+
+use std::{collections::BTreeSet, process::ExitCode};
+
+use bevy::reflect::Struct;
+use bevy_log_events::{
+    settings_io::{self, LoggedEventsSettings},
+    EventSettings,
+};
+
+const USAGE: &str = "\
+Usage: log-settings <command>
+
+Commands:
+  list <file>                 List the events of the file with their level
+  validate <file>...          Check that the files hold valid settings
+  enable <file> <event>...    Enable the events in the file
+  disable <file> <event>...   Disable the events in the file
+  diff <file> <other file>    Show the events that differ between two files";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["list", path] => list(path),
+        ["validate", paths @ ..] if !paths.is_empty() => validate(paths),
+        ["enable", path, names @ ..] if !names.is_empty() => set_enabled(path, names, true),
+        ["disable", path, names @ ..] if !names.is_empty() => set_enabled(path, names, false),
+        ["diff", path, other] => diff(path, other),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load(path: &str) -> Result<LoggedEventsSettings, String> {
+    settings_io::load(path).map_err(|err| format!("{}: {}", path, err))
+}
+
+fn list(path: &str) -> Result<bool, String> {
+    let settings = load(path)?;
+    for (name, event_settings) in &settings.events_settings {
+        let state = if event_settings.enabled { "on " } else { "off" };
+        println!("{} {:<5} {}", state, event_settings.level.as_str(), name);
+    }
+    Ok(true)
+}
+
+/// Whether every file can be read, written back without losing anything, and only holds
+/// valid regular expressions.
+fn validate(paths: &[&str]) -> Result<bool, String> {
+    let mut valid = true;
+    for path in paths {
+        match check(path) {
+            Ok(()) => println!("{}: ok", path),
+            Err(err) => {
+                println!("{}: {}", path, err);
+                valid = false;
+            }
+        }
+    }
+    Ok(valid)
+}
+
+fn check(path: &str) -> Result<(), String> {
+    let settings = settings_io::load(path).map_err(|err| err.to_string())?;
+    let round_trip = settings_io::round_trip(&settings).map_err(|err| err.to_string())?;
+    if round_trip != settings {
+        return Err("the settings change when they are written back".to_string());
+    }
+    for (name, filter) in &settings.entity_filters {
+        if let Err(err) = regex::Regex::new(filter) {
+            return Err(format!(
+                "the entity filter of {} is not valid: {}",
+                name, err
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Set the [enabled](EventSettings::enabled) field of the events `names`, adding them
+/// with the default settings when they are not in the file yet.
+fn set_enabled(path: &str, names: &[&str], enabled: bool) -> Result<bool, String> {
+    let mut settings = load(path)?;
+    for name in names {
+        settings
+            .events_settings
+            .entry(name.to_string())
+            .or_default()
+            .enabled = enabled;
+    }
+    settings_io::save(path, &settings).map_err(|err| err.to_string())?;
+    Ok(true)
+}
+
+/// Print the events added (`+`), removed (`-`) and changed (`~`) from `path` to `other`.
+/// This succeeds only when there is no difference, like `diff`.
+fn diff(path: &str, other: &str) -> Result<bool, String> {
+    let settings = load(path)?;
+    let other_settings = load(other)?;
+    let names: BTreeSet<_> = settings
+        .events_settings
+        .keys()
+        .chain(other_settings.events_settings.keys())
+        .collect();
+    let mut same = true;
+    for name in names {
+        let before = settings.events_settings.get(name);
+        let after = other_settings.events_settings.get(name);
+        match (before, after) {
+            (Some(before), Some(after)) if before != after => {
+                println!("~ {}", name);
+                print_changes(before, after);
+            }
+            (Some(_), None) => println!("- {}", name),
+            (None, Some(_)) => println!("+ {}", name),
+            _ => continue,
+        }
+        same = false;
+    }
+    let without_events = |settings: &LoggedEventsSettings| LoggedEventsSettings {
+        events_settings: Default::default(),
+        ..settings.clone()
+    };
+    if without_events(&settings) != without_events(&other_settings) {
+        println!("~ the plugin settings differ");
+        same = false;
+    }
+    Ok(same)
+}
+
+fn print_changes(before: &EventSettings, after: &EventSettings) {
+    if before.level != after.level {
+        println!("    level: {} -> {}", before.level, after.level);
+    }
+    for (index, field) in before.iter_fields().enumerate() {
+        let (Some(name), Some(other)) = (before.name_at(index), after.field_at(index)) else {
+            continue;
+        };
+        if field.reflect_partial_eq(other) != Some(true) {
+            println!("    {}: {:?} -> {:?}", name, field, other);
+        }
+    }
+}
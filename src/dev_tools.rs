@@ -0,0 +1,161 @@
+//! Change the settings of the logged events without the settings window, for projects
+//! using their own developer tools instead of egui.
+//!
+//! Settings are changed with short text commands that can be sent from a dev console
+//! with [run_command], or bound to keys with the [DevToolsAdapter] :
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_log_events::dev_tools::DevToolsAdapter;
+//! # let mut app = App::new();
+//! app.add_plugins(
+//!     DevToolsAdapter::default()
+//!         .bind(KeyCode::F1, "toggle")
+//!         .bind(KeyCode::F2, "toggle my_game::DamageEvent"),
+//! );
+//! ```
+//!
+//! The commands are :
+//! - `toggle`, `enable` and `disable` to change whether the plugin logs anything.
+//! - `toggle <event>`, `enable <event>` and `disable <event>` to change whether an event
+//!   is logged.
+//! - `level <event> <level>` to change the [Level] at which an event is logged.
+//! - `list [filter]` to list the events matching the
+//!   [filter expression](crate::ui_model::FilterExpr) `filter`, like `list level>=DEBUG`.
+//!
+//! Events are named like in the settings file. The settings are read and changed through
+//! the [ui_model](crate::ui_model), like any other UI could do.
+
+use bevy::{log::Level, prelude::*};
+
+use crate::{
+    ui_model::{self, event_settings, set_event_settings, FilterExpr},
+    FilterPreset, LogEventsError, LogEventsPluginSettings, LOG_TARGET,
+};
+
+/// The [Plugin] running [commands](self) when their key is pressed. It must be added
+/// after the [LogEventsPlugin](crate::LogEventsPlugin) and the `InputPlugin`.
+#[derive(Default)]
+pub struct DevToolsAdapter {
+    bindings: Vec<(KeyCode, String)>,
+}
+
+impl DevToolsAdapter {
+    /// Run `command` each time `key` is pressed.
+    pub fn bind(mut self, key: KeyCode, command: impl Into<String>) -> Self {
+        self.bindings.push((key, command.into()));
+        self
+    }
+}
+
+impl Plugin for DevToolsAdapter {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(KeyBindings(self.bindings.clone()))
+            .add_systems(Update, run_bound_commands);
+    }
+}
+
+#[derive(Resource)]
+struct KeyBindings(Vec<(KeyCode, String)>);
+
+fn run_bound_commands(world: &mut World) {
+    let Some(keys) = world.get_resource::<ButtonInput<KeyCode>>() else {
+        return;
+    };
+    let commands: Vec<_> = world
+        .resource::<KeyBindings>()
+        .0
+        .iter()
+        .filter(|(key, _)| keys.just_pressed(*key))
+        .map(|(_, command)| command.clone())
+        .collect();
+    for command in commands {
+        match run_command(world, &command) {
+            Ok(done) => info!(target: LOG_TARGET, "{}", done),
+            Err(err) => warn!(target: LOG_TARGET, "Could not run \"{}\": {}", command, err),
+        }
+    }
+}
+
+/// Run one of the [commands](self) on the plugin running in `world`, returning a
+/// description of what was done.
+pub fn run_command(world: &mut World, command: &str) -> Result<String, LogEventsError> {
+    if !world.contains_resource::<LogEventsPluginSettings>() {
+        return Err(LogEventsError::MissingResource(std::any::type_name::<
+            LogEventsPluginSettings,
+        >()));
+    }
+    let words: Vec<_> = command.split_whitespace().collect();
+    match words.as_slice() {
+        [action] if *action != "list" => {
+            let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings>();
+            plugin_settings.enabled = new_enabled(action, plugin_settings.enabled)?;
+            Ok(format!(
+                "Logging events is {}",
+                enabled_text(plugin_settings.enabled)
+            ))
+        }
+        ["list", filter @ ..] => {
+            let filter = filter.join(" ");
+            let expression = (!filter.is_empty())
+                .then(|| FilterExpr::parse(&filter))
+                .transpose()
+                .map_err(|err| invalid(format!("\"{}\" is not a valid filter: {}", filter, err)))?;
+            let rows: Vec<_> = ui_model::rows(world, &FilterPreset::default())
+                .into_iter()
+                .filter(|row| {
+                    expression
+                        .as_ref()
+                        .is_none_or(|expression| expression.matches(row))
+                })
+                .collect();
+            let mut text = format!("{} events", rows.len());
+            for row in rows {
+                text += &format!(
+                    "\n{} ({} at {})",
+                    row.name,
+                    enabled_text(row.logged),
+                    row.settings.level
+                );
+            }
+            Ok(text)
+        }
+        ["level", name, level] => {
+            let level: Level = level
+                .parse()
+                .map_err(|_| invalid(format!("\"{}\" is not a log level", level)))?;
+            let mut settings = event_settings(world, name)?;
+            settings.level = level;
+            set_event_settings(world, name, settings)?;
+            Ok(format!("{} is logged at {}", name, level))
+        }
+        [action, name] => {
+            let mut settings = event_settings(world, name)?;
+            let enabled = new_enabled(action, settings.enabled)?;
+            settings.enabled = enabled;
+            set_event_settings(world, name, settings)?;
+            Ok(format!("{} is {}", name, enabled_text(enabled)))
+        }
+        _ => Err(invalid(format!("\"{}\" is not a command", command))),
+    }
+}
+
+fn new_enabled(action: &str, enabled: bool) -> Result<bool, LogEventsError> {
+    match action {
+        "toggle" => Ok(!enabled),
+        "enable" => Ok(true),
+        "disable" => Ok(false),
+        _ => Err(invalid(format!("\"{}\" is not a command", action))),
+    }
+}
+
+fn enabled_text(enabled: bool) -> &'static str {
+    if enabled {
+        "enabled"
+    } else {
+        "disabled"
+    }
+}
+
+fn invalid(reason: String) -> LogEventsError {
+    LogEventsError::InvalidCommand(reason)
+}
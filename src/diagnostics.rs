@@ -0,0 +1,215 @@
+//! Introspection of the memory used by the plugin, to make sure it stays reasonable
+//! during long sessions.
+
+use std::{any::TypeId, mem::size_of, time::Duration};
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::*,
+    time::common_conditions::on_timer,
+    utils::HashMap,
+};
+
+use crate::{
+    settings_io::LoggedEventsSettings,
+    settings_window::LogEventsWindowState,
+    systems::{LogSettingsIds, RegisteredEvent},
+    ChannelSettings, EventSettings, LogEventsPluginSettings, LogInstance, LoggedEventSettings,
+};
+
+/// An estimation of the memory used by the [LogEventsPlugin](crate::LogEventsPlugin).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MemoryUsage {
+    /// The number of [LoggedEventSettings] resources.
+    pub settings_resources: usize,
+    /// The number of settings snapshots kept by the undo and redo history of the window.
+    pub history_entries: usize,
+    /// The number of entries in the maps of the plugin: the statistics of the
+    /// registered events, the templates, the pinned and favorite events, the channels,
+    /// the filter presets and the settings loaded from the file.
+    pub map_entries: usize,
+    /// The number of digests currently gathering events.
+    pub pending_digests: usize,
+    /// A rough estimation of the bytes used by all the above, not counting the
+    /// allocator overhead.
+    pub estimated_bytes: usize,
+}
+
+impl MemoryUsage {
+    /// The [DiagnosticPath] of [MemoryUsage::settings_resources].
+    pub const SETTINGS_RESOURCES: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_log_events/settings_resources");
+    /// The [DiagnosticPath] of [MemoryUsage::history_entries].
+    pub const HISTORY_ENTRIES: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_log_events/history_entries");
+    /// The [DiagnosticPath] of [MemoryUsage::map_entries].
+    pub const MAP_ENTRIES: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_log_events/map_entries");
+    /// The [DiagnosticPath] of [MemoryUsage::pending_digests].
+    pub const PENDING_DIGESTS: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_log_events/pending_digests");
+    /// The [DiagnosticPath] of [MemoryUsage::estimated_bytes].
+    pub const ESTIMATED_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("bevy_log_events/estimated_bytes");
+
+    pub(crate) fn measure<'a, I: LogInstance>(
+        ids: &LogSettingsIds<I>,
+        plugin_settings: &LogEventsPluginSettings<I>,
+        history: impl Iterator<Item = &'a LoggedEventsSettings>,
+    ) -> Self {
+        let mut usage = Self {
+            settings_resources: ids.len(),
+            map_entries: ids.len()
+                + plugin_settings.templates.len()
+                + plugin_settings.pinned.len()
+                + plugin_settings.favorites.len()
+                + plugin_settings.filter_presets.len()
+                + plugin_settings.channels.len()
+                + plugin_settings.previous_settings.len(),
+            ..default()
+        };
+        for (name, registered) in ids.iter() {
+            usage.estimated_bytes += size_of::<LoggedEventSettings<()>>()
+                + size_of::<RegisteredEvent>()
+                + name.len()
+                + registered.channel.as_ref().map_or(0, String::len);
+            let activity = registered.stats.lock();
+            if let Some(digest) = &activity.digest {
+                usage.pending_digests += 1;
+                usage.estimated_bytes += digest.size();
+            }
+            usage.estimated_bytes += activity.last_payload.as_ref().map_or(0, String::capacity);
+            usage.estimated_bytes += activity.last_line.as_ref().map_or(0, String::capacity);
+        }
+        usage.estimated_bytes += plugin_settings
+            .templates
+            .iter()
+            .map(|(name, template)| name.len() + template.len())
+            .sum::<usize>()
+            + plugin_settings
+                .channels
+                .keys()
+                .map(|name| name.len() + size_of::<ChannelSettings>())
+                .sum::<usize>()
+            + plugin_settings
+                .pinned
+                .iter()
+                .chain(&plugin_settings.favorites)
+                .map(String::len)
+                .sum::<usize>()
+            + events_bytes(&plugin_settings.previous_settings);
+        for snapshot in history {
+            usage.history_entries += 1;
+            usage.estimated_bytes += size_of::<LoggedEventsSettings>()
+                + events_bytes(&snapshot.events_settings)
+                + snapshot
+                    .templates
+                    .iter()
+                    .map(|(name, template)| name.len() + template.len())
+                    .sum::<usize>();
+        }
+        usage
+    }
+}
+
+fn events_bytes<'a>(events: impl IntoIterator<Item = (&'a String, &'a EventSettings)>) -> usize {
+    events
+        .into_iter()
+        .map(|(name, _)| name.len() + size_of::<EventSettings>())
+        .sum()
+}
+
+/// Estimate the memory currently used by the default instance of the
+/// [LogEventsPlugin](crate::LogEventsPlugin).
+///
+/// # Panics
+///
+/// Panics if the [LogEventsPlugin](crate::LogEventsPlugin) was not added.
+pub fn memory_usage(world: &World) -> MemoryUsage {
+    instance_memory_usage::<()>(world)
+}
+
+/// Like [memory_usage] for the instance `I`.
+fn instance_memory_usage<I: LogInstance>(world: &World) -> MemoryUsage {
+    let history = world
+        .get_resource::<LogEventsWindowState<I>>()
+        .into_iter()
+        .flat_map(LogEventsWindowState::history);
+    MemoryUsage::measure(
+        world.resource::<LogSettingsIds<I>>(),
+        world.resource::<LogEventsPluginSettings<I>>(),
+        history,
+    )
+}
+
+/// The last [MemoryUsage] of each instance of the [LogEventsPlugin](crate::LogEventsPlugin),
+/// added up in the diagnostics.
+#[derive(Resource, Default)]
+struct InstanceUsages(HashMap<TypeId, MemoryUsage>);
+
+/// How often the memory usage is measured.
+const MEASURE_PERIOD: Duration = Duration::from_secs(1);
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    app.add_systems(
+        Last,
+        measure_memory_usage::<I>
+            .run_if(on_timer(MEASURE_PERIOD))
+            .before(report_memory_usage),
+    );
+    if app.world().contains_resource::<InstanceUsages>() {
+        return;
+    }
+    for path in [
+        MemoryUsage::SETTINGS_RESOURCES,
+        MemoryUsage::HISTORY_ENTRIES,
+        MemoryUsage::MAP_ENTRIES,
+        MemoryUsage::PENDING_DIGESTS,
+    ] {
+        app.register_diagnostic(Diagnostic::new(path));
+    }
+    app.register_diagnostic(Diagnostic::new(MemoryUsage::ESTIMATED_BYTES).with_suffix("B"))
+        .init_resource::<InstanceUsages>()
+        .add_systems(
+            Last,
+            report_memory_usage.run_if(resource_changed::<InstanceUsages>),
+        );
+}
+
+fn measure_memory_usage<I: LogInstance>(
+    ids: Res<LogSettingsIds<I>>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    window_state: Option<Res<LogEventsWindowState<I>>>,
+    mut usages: ResMut<InstanceUsages>,
+) {
+    let history = window_state
+        .as_deref()
+        .into_iter()
+        .flat_map(LogEventsWindowState::history);
+    let usage = MemoryUsage::measure(&ids, &plugin_settings, history);
+    usages.0.insert(TypeId::of::<I>(), usage);
+}
+
+fn report_memory_usage(usages: Res<InstanceUsages>, mut diagnostics: Diagnostics) {
+    let mut usage = MemoryUsage::default();
+    for instance in usages.0.values() {
+        usage.settings_resources += instance.settings_resources;
+        usage.history_entries += instance.history_entries;
+        usage.map_entries += instance.map_entries;
+        usage.pending_digests += instance.pending_digests;
+        usage.estimated_bytes += instance.estimated_bytes;
+    }
+    diagnostics.add_measurement(&MemoryUsage::SETTINGS_RESOURCES, || {
+        usage.settings_resources as f64
+    });
+    diagnostics.add_measurement(&MemoryUsage::HISTORY_ENTRIES, || {
+        usage.history_entries as f64
+    });
+    diagnostics.add_measurement(&MemoryUsage::MAP_ENTRIES, || usage.map_entries as f64);
+    diagnostics.add_measurement(&MemoryUsage::PENDING_DIGESTS, || {
+        usage.pending_digests as f64
+    });
+    diagnostics.add_measurement(&MemoryUsage::ESTIMATED_BYTES, || {
+        usage.estimated_bytes as f64
+    });
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Display, Formatter};
+
+/// The errors that can happen while reading or writing the settings of the plugin or
+/// while formatting a log.
+#[derive(Debug)]
+pub enum LogEventsError {
+    /// The settings file could not be read or written.
+    Io(std::io::Error),
+    /// The settings file does not contain valid settings.
+    Ron(ron::error::SpannedError),
+    /// The settings could not be serialized.
+    Serialize(ron::Error),
+    /// The settings file does not contain valid JSON settings, or the settings could not
+    /// be serialized to JSON.
+    Json(serde_json::Error),
+    /// The settings file does not contain valid TOML settings.
+    Toml(toml::de::Error),
+    /// The settings could not be serialized to TOML.
+    TomlSerialize(toml::ser::Error),
+    /// A log could not be formatted.
+    Format(std::fmt::Error),
+    /// A [Resource](bevy::prelude::Resource) of the plugin is missing from the
+    /// [World](bevy::prelude::World), usually because the
+    /// [LogEventsPlugin](crate::LogEventsPlugin) was not added. Contains the name of
+    /// the missing resource.
+    MissingResource(&'static str),
+    /// A command given to [run_command](crate::dev_tools::run_command) is not valid.
+    /// Contains the reason why.
+    InvalidCommand(String),
+    /// No event is registered under this name.
+    UnknownEvent(String),
+    /// A [FilterExpr](crate::ui_model::FilterExpr) is not valid. Contains the reason why.
+    InvalidFilter(String),
+    /// The settings read back from a file differ from the ones written in it, see
+    /// [check_file](crate::settings_io::check_file). Contains the path of the file.
+    RoundTrip(std::path::PathBuf),
+}
+
+impl Display for LogEventsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogEventsError::Io(err) => write!(f, "{}", err),
+            LogEventsError::Ron(err) => write!(f, "{}", err),
+            LogEventsError::Serialize(err) => write!(f, "{}", err),
+            LogEventsError::Json(err) => write!(f, "{}", err),
+            LogEventsError::Toml(err) => write!(f, "{}", err),
+            LogEventsError::TomlSerialize(err) => write!(f, "{}", err),
+            LogEventsError::Format(err) => write!(f, "{}", err),
+            LogEventsError::MissingResource(name) => {
+                write!(f, "the resource {} does not exist", name)
+            }
+            LogEventsError::InvalidCommand(reason) | LogEventsError::InvalidFilter(reason) => {
+                write!(f, "{}", reason)
+            }
+            LogEventsError::UnknownEvent(name) => write!(f, "\"{}\" is not a logged event", name),
+            LogEventsError::RoundTrip(path) => {
+                write!(f, "the settings of {:?} change once written again", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LogEventsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LogEventsError::Io(err) => Some(err),
+            LogEventsError::Ron(err) => Some(err),
+            LogEventsError::Serialize(err) => Some(err),
+            LogEventsError::Json(err) => Some(err),
+            LogEventsError::Toml(err) => Some(err),
+            LogEventsError::TomlSerialize(err) => Some(err),
+            LogEventsError::Format(err) => Some(err),
+            LogEventsError::MissingResource(_)
+            | LogEventsError::InvalidCommand(_)
+            | LogEventsError::UnknownEvent(_)
+            | LogEventsError::InvalidFilter(_)
+            | LogEventsError::RoundTrip(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for LogEventsError {
+    fn from(err: std::io::Error) -> Self {
+        LogEventsError::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for LogEventsError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        LogEventsError::Ron(err)
+    }
+}
+
+impl From<std::fmt::Error> for LogEventsError {
+    fn from(err: std::fmt::Error) -> Self {
+        LogEventsError::Format(err)
+    }
+}
+
+impl From<ron::Error> for LogEventsError {
+    fn from(err: ron::Error) -> Self {
+        LogEventsError::Serialize(err)
+    }
+}
+
+impl From<serde_json::Error> for LogEventsError {
+    fn from(err: serde_json::Error) -> Self {
+        LogEventsError::Json(err)
+    }
+}
+
+impl From<toml::de::Error> for LogEventsError {
+    fn from(err: toml::de::Error) -> Self {
+        LogEventsError::Toml(err)
+    }
+}
+
+impl From<toml::ser::Error> for LogEventsError {
+    fn from(err: toml::ser::Error) -> Self {
+        LogEventsError::TomlSerialize(err)
+    }
+}
@@ -0,0 +1,70 @@
+//! Draws a sphere on the entities targeted by the events logged with
+//! [gizmo](crate::EventSettings::gizmo) enabled.
+
+use std::time::Duration;
+
+use bevy::{gizmos::config::GizmoConfigStore, log::Level, prelude::*, utils::Instant};
+
+use crate::{
+    settings_window::level_color, systems::LogSettingsIds, LogEventsPluginSettings, LogInstance,
+};
+
+/// How long a sphere stays on an entity after its log, fading out.
+const GIZMO_DURATION: Duration = Duration::from_secs(1);
+
+/// The radius of the spheres, scaled by the largest scale of the entity.
+const GIZMO_RADIUS: f32 = 0.5;
+
+/// An entity logged less than [GIZMO_DURATION] ago.
+struct Marker {
+    entity: Entity,
+    level: Level,
+    logged: Instant,
+}
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        draw_gizmos::<I>
+            .after(TransformSystem::TransformPropagate)
+            .run_if(resource_exists::<GizmoConfigStore>),
+    );
+}
+
+fn draw_gizmos<I: LogInstance>(
+    mut gizmos: Gizmos,
+    mut markers: Local<Vec<Marker>>,
+    log_settings_ids: Res<LogSettingsIds<I>>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    transforms: Query<&GlobalTransform>,
+) {
+    let now = Instant::now();
+    for registered in log_settings_ids.values() {
+        let mut activity = registered.stats.lock();
+        markers.extend(
+            activity
+                .gizmo_entities
+                .drain(..)
+                .map(|(entity, level)| Marker {
+                    entity,
+                    level,
+                    logged: now,
+                }),
+        );
+    }
+    markers.retain(|marker| now.duration_since(marker.logged) < GIZMO_DURATION);
+    for marker in markers.iter() {
+        let Ok(transform) = transforms.get(marker.entity) else {
+            continue;
+        };
+        let color = level_color(marker.level, plugin_settings.level_palette);
+        let fade =
+            1.0 - now.duration_since(marker.logged).as_secs_f32() / GIZMO_DURATION.as_secs_f32();
+        let (scale, _, translation) = transform.to_scale_rotation_translation();
+        gizmos.sphere(
+            Isometry3d::from_translation(translation),
+            GIZMO_RADIUS * scale.max_element(),
+            Color::srgba_u8(color.r(), color.g(), color.b(), (fade * 255.0) as u8),
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! The last logs made by the plugin, kept so a debug console of the game can show them
+//! without capturing the output of [tracing](bevy::log::tracing).
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use bevy::{core::FrameCount, log::Level, prelude::*, utils::Instant};
+
+use crate::{
+    systems::{log_schedule, LogLine},
+    LogEventsPluginSettings, LogEventsSet, LogInstance,
+};
+
+/// A log of an [Event] kept by the [LogHistory].
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    /// The name of the [Event], as shown in the settings window.
+    pub name: String,
+    /// The [Level] of the log.
+    pub level: Level,
+    /// The [Entity] the [Event] targets or the component logged is on, if any.
+    pub entity: Option<Entity>,
+    /// When the log was made.
+    pub timestamp: Instant,
+    /// When the [Event] was sent, as far as it is known. The events read with an
+    /// [EventReader] are said to be sent at the end of the schedule of the main loop they
+    /// were sent in, the others when they were logged.
+    pub emitted: Instant,
+    /// The line logged, as written by [tracing](bevy::log::tracing).
+    pub text: String,
+    /// The [FrameCount] when the log was made, or 0 without the
+    /// [FrameCountPlugin](bevy::core::FrameCountPlugin).
+    pub frame: u32,
+}
+
+/// The last logs made by the plugin, the oldest first. This [Resource] only exists when
+/// the plugin is built with [with_log_history](crate::LogEventsPlugin::with_log_history).
+///
+/// The logs are added at the end of the [LogEventsSet], so the logs made by the
+/// observers during a frame are available from the next one. The logs added together are
+/// ordered by [emitted](LogEntry::emitted), so the events read with an [EventReader] are
+/// placed among the triggers logged right away rather than after them.
+///
+/// The summaries of the events in [digest](crate::EventSettings::digest) mode and the
+/// lines written by the plugin about itself are not kept.
+#[derive(Resource, Debug)]
+pub struct LogHistory {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+}
+
+impl LogHistory {
+    /// The logs kept, the oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LogEntry> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// The logs kept made at `level` or at a more severe level, the oldest first. With
+    /// [WARN](Level::WARN) this gives the warnings and the errors.
+    pub fn filter_by_level(&self, level: Level) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.level <= level)
+    }
+
+    /// The logs kept involving `entity`, the oldest first: the events targeting it and
+    /// the changes of its components.
+    pub fn filter_by_entity(&self, entity: Entity) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.entity == Some(entity))
+    }
+
+    /// Forget every log kept.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// The number of logs kept.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no log is kept.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The maximum number of logs kept, the oldest are dropped past it.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn extend(&mut self, entries: impl IntoIterator<Item = LogEntry>) {
+        self.entries.extend(entries);
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        self.entries.drain(..excess);
+    }
+}
+
+/// The logs made since the end of the last [LogEventsSet], moved to the [LogHistory]
+/// there. It is shared with the [LogEventsPluginSettings] as the logs are made from
+/// places without access to the [World].
+#[derive(Default)]
+pub(crate) struct PendingEntries {
+    frame: AtomicU32,
+    entries: Mutex<Vec<LogEntry>>,
+}
+
+impl PendingEntries {
+    pub(crate) fn push(&self, line: &LogLine, level: Level, text: &str) {
+        let timestamp = Instant::now();
+        let entry = LogEntry {
+            name: line.name.to_string(),
+            level,
+            entity: line.target,
+            timestamp,
+            emitted: line.emitted.unwrap_or(timestamp),
+            text: text.to_string(),
+            frame: self.frame.load(Ordering::Relaxed),
+        };
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(entry);
+    }
+}
+
+/// Add the [LogHistory], shared by the instances of the
+/// [LogEventsPlugin](crate::LogEventsPlugin) and keeping the largest `capacity` given.
+pub(crate) fn plugin<I: LogInstance>(app: &mut App, capacity: usize) {
+    let schedule = log_schedule::<I>(app.world());
+    match app.world_mut().get_resource_mut::<LogHistory>() {
+        Some(mut history) => history.capacity = history.capacity.max(capacity),
+        None => {
+            app.insert_resource(LogHistory {
+                entries: VecDeque::with_capacity(capacity),
+                capacity,
+            });
+        }
+    }
+    app.add_systems(First, update_frame::<I>)
+        .add_systems(schedule, collect_entries::<I>.after(LogEventsSet));
+}
+
+fn update_frame<I: LogInstance>(
+    frame: Option<Res<FrameCount>>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+) {
+    if let (Some(frame), Some(pending)) = (frame, &plugin_settings.history) {
+        pending.frame.store(frame.0, Ordering::Relaxed);
+    }
+}
+
+fn collect_entries<I: LogInstance>(
+    mut history: ResMut<LogHistory>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+) {
+    let Some(pending) = &plugin_settings.history else {
+        return;
+    };
+    let mut entries = std::mem::take(
+        &mut *pending
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    if !entries.is_empty() {
+        entries.sort_by_key(|entry| entry.emitted);
+        history.extend(entries);
+    }
+}
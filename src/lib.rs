@@ -13,77 +13,528 @@ compile_error!(
 It will be made available again when the \"bevy_editor_pls\" will be updated to Bevy 0.15."
 );
 // mod editor_window;
+#[cfg(all(feature = "enabled", feature = "audio"))]
+mod audio;
+#[cfg(feature = "enabled")]
+pub mod dev_tools;
+#[cfg(feature = "enabled")]
+pub mod diagnostics;
+#[cfg(feature = "enabled")]
+mod error;
+#[cfg(all(feature = "enabled", feature = "gizmos"))]
+mod gizmos;
+#[cfg(feature = "enabled")]
+pub mod history;
+#[cfg(feature = "enabled")]
+mod log_files;
+#[cfg(feature = "enabled")]
+mod log_viewer;
+#[cfg(all(feature = "enabled", feature = "metrics"))]
+mod metrics;
+#[cfg(all(feature = "enabled", feature = "notifications"))]
+mod notifications;
+#[cfg(feature = "enabled")]
+mod send_schedule;
+#[cfg(feature = "enabled")]
+pub mod settings_io;
 #[cfg(feature = "enabled")]
 mod settings_window;
 #[cfg(feature = "enabled")]
 mod systems;
+#[cfg(all(feature = "enabled", feature = "ui"))]
+mod ui;
+#[cfg(feature = "enabled")]
+pub mod ui_model;
 #[cfg(feature = "enabled")]
 mod utils;
+#[cfg(feature = "enabled")]
+mod watch;
 
 #[cfg(feature = "enabled")]
-use std::{any::type_name, collections::BTreeMap};
-use std::{marker::PhantomData, path::PathBuf};
+use std::any::type_name;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    path::PathBuf,
+    time::Duration,
+};
 
-use bevy::{log::Level, prelude::*, state::state::FreelyMutableState};
+use bevy::{
+    ecs::{
+        query::QueryFilter,
+        schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleLabel},
+    },
+    log::Level,
+    prelude::*,
+    state::state::FreelyMutableState,
+};
+
+#[cfg(feature = "enabled")]
+use std::sync::atomic::{AtomicU64, Ordering};
 
+#[cfg(feature = "enabled")]
+use crossbeam_channel::{bounded, Receiver, Sender};
 #[cfg(feature = "enabled")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "enabled")]
-use systems::{log_component, log_event, log_triggered, register_component, register_event};
+use bevy::input::gamepad::{
+    GamepadAxisChangedEvent, GamepadButtonStateChangedEvent, GamepadConnectionEvent,
+};
+#[cfg(feature = "enabled")]
+use systems::{
+    add_log_observer, add_runtime_log_observer, add_to_log_schedule, cache_component, flush_burst,
+    log_cached_component, log_component, log_event, log_formatted_event, log_gamepad_event,
+    log_reflected_event, log_schedule, log_triggered, prune_component_cache, queue_registration,
+    register_component, register_event, register_sent_event, CurrentLogChannel, CurrentLogGroup,
+    CurrentReaderStart, EventFormatter, GamepadEvent, InstanceSet, LastComponentValues,
+};
+#[cfg(feature = "enabled")]
+use utils::{
+    deserialize_format, deserialize_level, deserialize_optional_level, serialize_format,
+    serialize_level, serialize_optional_level, trigger_name,
+};
+
 #[cfg(feature = "enabled")]
-use utils::{deserialize_level, serialize_level, trigger_name};
+pub use error::LogEventsError;
+
+/// Derive macro registering an [Event] for [log_all_derived](LogEvent::log_all_derived).
+#[cfg(feature = "derive")]
+pub use bevy_log_events_derive::LoggedEvent;
+
+/// Used by the code generated by `#[derive(LoggedEvent)]`, not part of the public API.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    use bevy::prelude::*;
+
+    pub use inventory;
+
+    use crate::LogEvent;
+
+    pub struct DerivedEvent(pub fn(&mut App));
+
+    inventory::collect!(DerivedEvent);
+
+    pub fn register<E>(app: &mut App)
+    where
+        E: Event + std::fmt::Debug,
+    {
+        app.log_event::<E>();
+    }
+}
 
 /// Re-export of everything you need.
 pub mod prelude {
     pub use super::{
-        EventSettings, LogEvent, LogEventsPlugin, LogEventsPluginSettings, LogEventsSet,
-        LoggedEventSettings, RegisterEventsSet,
+        display_entity, ChannelSettings, DisplayEntity, EntityLabeler, EntitySource, EventSettings,
+        FilterPreset, LevelPalette, LevelStyle, LogBackend, LogEvent, LogEventWorld,
+        LogEventsPlugin, LogEventsPluginSettings, LogEventsSet, LogFormat, LogGroupToken,
+        LogHandle, LogInstance, LogInstanceApp, LogOutput, LoggedComponents, LoggedEventSettings,
+        NameStyle, ReaderStart, RegisterEventsSet, SavePolicy, SettingsFormat, SoundCue, UiDensity,
     };
 }
 
 /// The [Plugin] to add to enable the logging of [Event].
-pub struct LogEventsPlugin {
+///
+/// Several independent instances of the plugin can be added to the same [App], each
+/// with its own settings file and settings window, by giving them different
+/// [LogInstance] markers, see [new_instance](LogEventsPlugin::new_instance). The
+/// default instance is `()`.
+pub struct LogEventsPlugin<I = ()> {
     /// Path were the settings will be stored and loaded. If the specified file
     /// can not be found a new one will be created.
     pub settings_path: PathBuf,
+    schedule: InternedScheduleLabel,
+    after: Vec<InternedSystemSet>,
+    before: Vec<InternedSystemSet>,
+    save_on_panic: bool,
+    add_egui: bool,
+    short_names: bool,
+    save_policy: SavePolicy,
+    fallback_dir: Option<PathBuf>,
+    disabled_groups: BTreeSet<String>,
+    level_style: LevelStyle,
+    reader_start: ReaderStart,
+    log_history: Option<usize>,
+    base_path: Option<PathBuf>,
+    format: Option<SettingsFormat>,
+    backend: LogBackend,
+    hot_reload: Option<Duration>,
+    formatters: BTreeMap<(std::any::TypeId, String), Formatter>,
+    #[cfg(feature = "metrics")]
+    metrics_address: Option<std::net::SocketAddr>,
+    _instance: PhantomData<fn() -> I>,
 }
 
+/// A marker type telling apart the instances of the [LogEventsPlugin] added to the same
+/// [App], like a `struct NetworkLogs;`. Every type can be used as a marker.
+///
+/// Each instance has its own [LogEventsPluginSettings<I>](LogEventsPluginSettings),
+/// settings file and settings window, and logs the events registered inside its
+/// [log_instance](LogEvent::log_instance). An event can only be logged by one instance.
+pub trait LogInstance: Send + Sync + 'static {}
+
+impl<T: Send + Sync + 'static> LogInstance for T {}
+
 impl LogEventsPlugin {
     /// Lets you specify the location were the settings will be stored.
     pub fn new(settings_path: impl Into<PathBuf>) -> Self {
+        Self::new_instance(settings_path)
+    }
+}
+
+impl<I: LogInstance> LogEventsPlugin<I> {
+    /// Like [new](LogEventsPlugin::new) for the instance `I` of the plugin, whose events
+    /// are registered inside [log_instance](LogEvent::log_instance).
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct Connected;
+    /// struct NetworkLogs;
+    ///
+    /// App::new()
+    ///     .add_plugins((
+    ///         LogEventsPlugin::new("log_settings.ron"),
+    ///         LogEventsPlugin::<NetworkLogs>::new_instance("network_log_settings.ron"),
+    ///     ))
+    ///     .log_instance::<NetworkLogs>(|app| {
+    ///         app.add_and_log_event::<Connected>();
+    ///     });
+    /// ```
+    pub fn new_instance(settings_path: impl Into<PathBuf>) -> Self {
         Self {
             settings_path: settings_path.into(),
+            schedule: Last.intern(),
+            after: Vec::new(),
+            before: Vec::new(),
+            save_on_panic: false,
+            add_egui: true,
+            short_names: false,
+            save_policy: SavePolicy::Overwrite,
+            fallback_dir: default_fallback_dir(),
+            disabled_groups: BTreeSet::new(),
+            level_style: LevelStyle::Uppercase,
+            reader_start: ReaderStart::Buffered,
+            log_history: None,
+            base_path: None,
+            format: None,
+            backend: LogBackend::Tracing,
+            hot_reload: None,
+            formatters: BTreeMap::new(),
+            #[cfg(feature = "metrics")]
+            metrics_address: None,
+            _instance: PhantomData,
         }
     }
+
+    /// Run the [LogEventsSet] in `schedule` instead of [Last], whether the events are
+    /// registered before or after the [LogEventsPlugin] is added.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Run the [LogEventsSet] after `set`, which must be in the same schedule.
+    pub fn after_set(mut self, set: impl SystemSet) -> Self {
+        self.after.push(set.intern());
+        self
+    }
+
+    /// Run the [LogEventsSet] before `set`, which must be in the same schedule.
+    pub fn before_set(mut self, set: impl SystemSet) -> Self {
+        self.before.push(set.intern());
+        self
+    }
+
+    /// Install a panic hook saving the settings before the program aborts, as they are
+    /// otherwise only saved on [AppExit]. The previous panic hook is still called after.
+    ///
+    /// The settings saved are the ones from at most a second before the panic.
+    pub fn save_on_panic(mut self) -> Self {
+        self.save_on_panic = true;
+        self
+    }
+
+    /// Whether the `EguiPlugin` is added when it is missing, defaults to true. When it
+    /// is missing and not added, the settings window is not available instead, see
+    /// [window_available](LogEventsPluginSettings::window_available).
+    pub fn add_egui_plugin(mut self, add: bool) -> Self {
+        self.add_egui = add;
+        self
+    }
+
+    /// Save the settings of the events under their type names without the module paths,
+    /// like `DamageEvent` instead of `my_game::combat::DamageEvent`, so moving an event to
+    /// another module does not lose its saved settings.
+    ///
+    /// When several events registered with the [App] have the same short name, they all
+    /// keep their full type name. An event registered later with [LogEventWorld] keeps its
+    /// full type name when its short name is already used. See also
+    /// [log_event_as](LogEvent::log_event_as).
+    pub fn with_short_names(mut self) -> Self {
+        self.short_names = true;
+        self
+    }
+
+    /// What is done when the settings file was changed by another instance of the program
+    /// using the same path when the settings are saved on exit, see [SavePolicy].
+    pub fn with_save_policy(mut self, policy: SavePolicy) -> Self {
+        self.save_policy = policy;
+        self
+    }
+
+    /// The directory where the settings are saved when the
+    /// [settings_path](LogEventsPlugin::settings_path) can not be written, like in installed
+    /// builds where `assets/` is read-only. The settings are then loaded from there as long
+    /// as it is the most recent file.
+    ///
+    /// Defaults to a directory named after the executable in the configuration directory of
+    /// the platform, like `~/.config/my_game` on Linux.
+    pub fn with_fallback_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.fallback_dir = Some(dir.into());
+        self
+    }
+
+    /// Do not save the settings anywhere else when the
+    /// [settings_path](LogEventsPlugin::settings_path) can not be written.
+    pub fn without_fallback_dir(mut self) -> Self {
+        self.fallback_dir = None;
+        self
+    }
+
+    /// Never log the events of the group `group`, whatever their settings are. See
+    /// [with_group](LogEvent::with_group) and
+    /// [disabled_groups](LogEventsPluginSettings::disabled_groups).
+    pub fn disable_group(mut self, group: impl Into<String>) -> Self {
+        self.disabled_groups.insert(group.into());
+        self
+    }
+
+    /// How the levels are written in the settings file, see [LevelStyle]. They are read
+    /// whatever their style is.
+    ///
+    /// This also applies to the files written with [save_world](settings_io::save_world),
+    /// the other functions of the [settings_io] module write the levels in uppercase.
+    pub fn with_level_style(mut self, style: LevelStyle) -> Self {
+        self.level_style = style;
+        self
+    }
+
+    /// Whether the events sent before an [Event] is registered are logged, see
+    /// [ReaderStart]. Defaults to [ReaderStart::Buffered].
+    ///
+    /// It can be changed for some events with [with_reader_start](LogEvent::with_reader_start).
+    pub fn with_reader_start(mut self, start: ReaderStart) -> Self {
+        self.reader_start = start;
+        self
+    }
+
+    /// Turn the values of type `T` into text with `formatter` when the format of their
+    /// event is the [Custom](LogFormat::Custom) one named `name`. `T` is the type of the
+    /// event, or the one of the component of [log_trigger](LogEvent::log_trigger).
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// #[derive(Event, Debug)]
+    /// struct Damage(f32);
+    ///
+    /// App::new().add_plugins(
+    ///     LogEventsPlugin::new("log_settings.ron")
+    ///         .with_formatter("hp", |damage: &Damage| format!("-{} HP", damage.0)),
+    /// );
+    /// ```
+    pub fn with_formatter<T: 'static>(
+        mut self,
+        name: impl Into<String>,
+        formatter: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let formatter: Formatter = std::sync::Arc::new(move |value| {
+            value
+                .downcast_ref::<T>()
+                .map(&formatter)
+                .unwrap_or_default()
+        });
+        self.formatters
+            .insert((std::any::TypeId::of::<T>(), name.into()), formatter);
+        self
+    }
+
+    /// Turn the values of type `T` into text with their [Display](std::fmt::Display)
+    /// implementation when the format of their event is [LogFormat::Display], see
+    /// [with_formatter](LogEventsPlugin::with_formatter).
+    pub fn with_display<T: std::fmt::Display + 'static>(self) -> Self {
+        self.with_formatter(LogFormat::Display.to_string(), T::to_string)
+    }
+
+    /// Keep the last `capacity` logs of the events in the
+    /// [LogHistory](history::LogHistory) resource, so a debug console of the game can
+    /// show them. There is no history by default.
+    pub fn with_log_history(mut self, capacity: usize) -> Self {
+        self.log_history = Some(capacity);
+        self
+    }
+
+    /// Read the settings from the file at `path` first, like a file committed with the
+    /// project, then apply the [settings_path](LogEventsPlugin::settings_path) over them
+    /// as a personal overlay. The file at `path` is never written, and the overlay only
+    /// keeps the events, the other entries and the values that differ from it when the
+    /// settings are saved on exit, see [OverlaySettings](settings_io::OverlaySettings).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(LogEventsPlugin::new("log_overlay.ron").with_base("assets/log_base.ron"));
+    /// ```
+    ///
+    /// See [apply_overlay](settings_io::LoggedEventsSettings::apply_overlay).
+    pub fn with_base(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base_path = Some(path.into());
+        self
+    }
+
+    /// Check every `interval` whether the settings file was changed outside of the
+    /// program, for example from a text editor, and apply the entries changed in it. The
+    /// settings changed at runtime and not in the file are kept. There is no hot reload by
+    /// default.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # let mut app = App::new();
+    /// app.add_plugins(LogEventsPlugin::default().with_hot_reload(Duration::from_secs(1)));
+    /// ```
+    pub fn with_hot_reload(mut self, interval: Duration) -> Self {
+        self.hot_reload = Some(interval);
+        self
+    }
+
+    /// Write the logs of the events with `backend`. Defaults to [LogBackend::Tracing].
+    ///
+    /// The lines written by the plugin about itself, like the errors while saving the
+    /// settings, always use [tracing](bevy::log::tracing).
+    pub fn with_backend(mut self, backend: LogBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Read and write the [settings_path](LogEventsPlugin::settings_path), its fallback
+    /// and the [base](LogEventsPlugin::with_base) file in `format`, whatever their
+    /// extension. By default the format of each file is chosen from its extension with
+    /// [SettingsFormat::from_path].
+    ///
+    /// The files loaded or saved from the settings window always use their extension.
+    pub fn with_format(mut self, format: SettingsFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Serve the number of logs of each event and whether it is enabled as Prometheus
+    /// metrics over HTTP on `address`, like `([0, 0, 0, 0], 9184)`. The metrics are
+    /// updated every second.
+    ///
+    /// Requires the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn serve_metrics(mut self, address: impl Into<std::net::SocketAddr>) -> Self {
+        self.metrics_address = Some(address.into());
+        self
+    }
 }
 
 impl Default for LogEventsPlugin {
     fn default() -> Self {
-        Self {
-            settings_path: "assets/log_settings.ron".into(),
-        }
+        Self::new("assets/log_settings.ron")
     }
 }
 
+/// The configuration directory of the platform, joined with the name of the executable.
+fn default_fallback_dir() -> Option<PathBuf> {
+    let home = || std::env::var_os("HOME").map(PathBuf::from);
+    let config_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home().map(|home| home.join(".config")))
+    };
+    let executable = std::env::current_exe().ok()?;
+    Some(config_dir?.join(executable.file_stem()?))
+}
+
 #[cfg(not(feature = "enabled"))]
-impl Plugin for LogEventsPlugin {
+impl<I: LogInstance> Plugin for LogEventsPlugin<I> {
     fn build(&self, _app: &mut App) {}
 }
 
+/// The target of every log made by the plugin, including the logs of the events, so they
+/// can be filtered with the `filter` of the [LogPlugin](bevy::log::LogPlugin), like
+/// `format!("wgpu=error,{}=warn", LOG_TARGET)`.
+///
+/// It can not be changed as [tracing](bevy::log::tracing) requires the targets to be
+/// known at compile time.
+pub const LOG_TARGET: &str = "bevy_log_events";
+
+/// The environment variable holding a [filter expression](ui_model::FilterExpr) choosing
+/// the events logged, like `BEVY_LOG_EVENTS_FILTER='name~"combat" || level<=WARN'`.
+///
+/// When it is set, the events matching it are enabled and the other ones disabled as they
+/// are registered, over the settings file. They can still be changed afterwards, and are
+/// saved on exit like the other changes.
+pub const FILTER_ENV_VAR: &str = "BEVY_LOG_EVENTS_FILTER";
+
+/// The maximum number of events that can be [pinned](LogEventsPluginSettings::pinned)
+/// from the settings window.
+pub const MAX_PINNED: usize = 10;
+
+/// The maximum number of components that can be [watched](LogEventsPluginSettings::watch)
+/// from the settings file.
+pub const MAX_WATCHED: usize = 32;
+
+/// A function called with the name of an [Event] and its log.
+pub type BreakHook = Box<dyn Fn(&str, &str) + Send + Sync>;
+
+/// A function showing extra widgets in the row of an [Event] in the settings window,
+/// called with the name of the event and its settings. See
+/// [add_row_widget](LogEventsPluginSettings::add_row_widget).
+#[cfg(feature = "enabled")]
+pub type RowWidget = Box<dyn Fn(&mut bevy_egui::egui::Ui, &str, &mut EventSettings) + Send + Sync>;
+
+/// Called each time an [Event] with [break_on_log](EventSettings::break_on_log) enabled
+/// is logged, with the name of the event and its log.
+///
+/// This function does nothing, it is only there for you to put a breakpoint on it in your
+/// debugger, for example with `break bevy_log_events::log_breakpoint` in gdb or lldb.
+#[inline(never)]
+pub fn log_breakpoint(name: &str, to_log: &str) {
+    std::hint::black_box((name, to_log));
+}
+
 /// The [SystemSet] were the [Event] are registred.
 ///
-/// This [SystemSet] is configured to run in the [Startup] schedule. This is were
-/// the saved [LoggedEventSettings] resources from the previous run of the program
-/// will be restored. After this set you can access these resources to read and write
-/// on them.
+/// The saved [LoggedEventSettings] resources from the previous run of the program are
+/// restored when the plugins are finished, whether the events were registered before or
+/// after the [LogEventsPlugin] was added. This [SystemSet] is configured to run in the
+/// [Startup] schedule and registers the events left, for example when the [App] is
+/// updated without calling [App::finish]. After this set you can access these resources
+/// to read and write on them.
+///
+/// Restoring the saved settings bypasses the change detection of the
+/// [LoggedEventSettings] and [LogEventsPluginSettings] resources, so it is not seen
+/// as a change made at runtime by systems using [resource_changed].
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RegisterEventsSet;
 
 /// The [SystemSet] were the [Event] registred with [log_event](LogEvent::log_event)
 /// and [add_and_log_event](LogEvent::add_and_log_event) will be log.
 ///
-/// This [SystemSet] is configured to run in the [Last] schedule at the end of each
+/// This [SystemSet] is configured to run by default in the [Last] schedule at the end of each
 /// frame and the events will be log one [Event] type at a time.
 /// So keep in mind that the events logged this way will be with a delay and not
 /// necessarily in the same order they were sent.
@@ -94,14 +545,27 @@ pub struct LogEventsSet;
 ///
 /// To modify how a particular [Event] will be logged you will need to access his
 /// [LoggedEventSettings] associated [Resource].
-#[derive(Clone, Copy)]
+///
+/// It implements [Reflect] so the settings window can show its fields without knowing them.
+#[derive(Clone, PartialEq, Debug, Reflect)]
+#[reflect(from_reflect = false)]
 #[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
 pub struct EventSettings {
     /// Whether the [Event] will be logged or not.
     pub enabled: bool,
-    /// If true use the pretty-printing debug flag `{:#?}` to log the [Event].
-    /// Otherwise use the compact-printing debug flag `{:?}`.
-    pub pretty: bool,
+    /// How the [Event] is turned into text, see [LogFormat].
+    ///
+    /// Settings files written before this field existed used a `pretty` boolean, which
+    /// is still read and mapped onto [LogFormat::Pretty] or [LogFormat::Compact].
+    #[cfg_attr(
+        feature = "enabled",
+        serde(
+            alias = "pretty",
+            serialize_with = "serialize_format",
+            deserialize_with = "deserialize_format"
+        )
+    )]
+    pub format: LogFormat,
     #[cfg_attr(
         feature = "enabled",
         serde(
@@ -110,95 +574,1120 @@ pub struct EventSettings {
         )
     )]
     /// The [Level] at which the [Event] will be logged.
+    #[reflect(ignore)]
     pub level: Level,
+    /// Only used by [log_trigger](LogEvent::log_trigger). If true, when the targeted
+    /// [Entity] does not have the logged [Component] a line will still be logged with
+    /// `<value unavailable>` in place of the [Component].
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub log_unavailable: bool,
+    /// If set, instead of logging each [Event] a summary is logged every `digest` seconds
+    /// with the number of events, their levels and an example of their log.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub digest: Option<f32>,
+    /// If true, each log of the [Event] is also shown as a desktop notification.
+    /// Only used with the `notifications` feature. The notifications are shown one at a
+    /// time, at most two per second, and are dropped when too many are waiting.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub notify: bool,
+    /// If true, [log_breakpoint] is called each time the [Event] is logged so a debugger
+    /// can stop right when it happens.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub break_on_log: bool,
+    /// If true, the [Event] will only be logged in builds with `debug_assertions`
+    /// enabled, so the same registrations can be kept in release builds.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub debug_only: bool,
+    /// If set, the [Event] stops being logged after `max_logs` logs during the run and a
+    /// last line tells that the cap was reached.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub max_logs: Option<u32>,
+    /// Only used by [log_trigger](LogEvent::log_trigger). If true, the ticks at which the
+    /// [Component] was added and last changed are appended to the log, along with how
+    /// many ticks ago it happened.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub change_ticks: bool,
+    /// Only used by [log_trigger](LogEvent::log_trigger). If true, the number of components
+    /// of the targeted [Entity] and the id of its archetype are appended to the log, which
+    /// tells whether the entity is being assembled or torn down.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub entity_info: bool,
+    /// Only used by [log_event](LogEvent::log_event) and its variants. If true, the
+    /// position of the [Event] among the events of its type sent during the frame is
+    /// appended to the log, like `#2 this frame`, so events sent by several systems can
+    /// be told apart. The events sent with an [EventWriter] are logged in the order they
+    /// were sent, followed by the ones sent through a [LogHandle] in the order they were
+    /// sent, as the two can not be ordered between them.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub frame_index: bool,
+    /// If true, the schedule of the main loop in which the [Event] was sent is appended to
+    /// the log, like `(sent in PreUpdate)`, or the one in which it was triggered for the
+    /// triggers, like `(triggered in Update)`. The events sent in a schedule run by another
+    /// one, like [FixedUpdate], are said to be sent in the outer schedule.
+    ///
+    /// Bevy 0.15 does not record the code location events are sent or triggered from, so
+    /// the schedule is what can be told about where they come from.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub send_schedule: bool,
+    /// Only used by [log_trigger](LogEvent::log_trigger) and
+    /// [log_trigger_cached](LogEvent::log_trigger_cached). If set, the logs made during a
+    /// frame are gathered and logged at the end of the frame, in a single line like
+    /// `OnInsert<Enemy> on 500 entities: first Enemy(12v1), Enemy { hp: 10 }` when there
+    /// are at least `aggregate_burst_threshold` of them.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub aggregate_burst_threshold: Option<u32>,
+    /// Where the logs of the [Event] are written, see [LogOutput].
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub output: LogOutput,
+    /// Only used for the events targeting an [Entity]. If true, a sphere is drawn for a
+    /// moment at the [GlobalTransform] of the entity each time the [Event] is logged, in
+    /// the color of its [Level]. Only used with the `gizmos` feature.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub gizmo: bool,
 }
 
 impl Default for EventSettings {
     fn default() -> Self {
         Self {
             enabled: true,
-            pretty: true,
+            format: LogFormat::Pretty,
             level: Level::INFO,
+            log_unavailable: false,
+            digest: None,
+            notify: false,
+            break_on_log: false,
+            debug_only: false,
+            max_logs: None,
+            change_ticks: false,
+            entity_info: false,
+            frame_index: false,
+            send_schedule: false,
+            aggregate_burst_threshold: None,
+            output: LogOutput::Tracing,
+            gizmo: false,
         }
     }
 }
 
-/// The settings used to configure the [LogEventsPlugin].
-#[derive(Resource)]
-pub struct LogEventsPluginSettings {
-    /// If false no [Event] will be logged.
-    pub enabled: bool,
-    /// Whether to show or not the window to configure the [LoggedEventSettings].
-    pub show_window: bool,
-    #[cfg(feature = "enabled")]
-    saved_settings: PathBuf,
-    #[cfg(feature = "enabled")]
-    previous_settings: BTreeMap<String, EventSettings>,
+/// How an [Event] is turned into text by [log_event](LogEvent::log_event).
+///
+/// The logged events only have to implement [Debug](std::fmt::Debug), so the formats
+/// needing more than that fall back to [Compact](LogFormat::Compact) for the events that
+/// can not use them.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Reflect)]
+#[non_exhaustive]
+pub enum LogFormat {
+    /// Use the compact-printing debug flag `{:?}`.
+    Compact,
+    /// Use the pretty-printing debug flag `{:#?}`.
+    #[default]
+    Pretty,
+    /// Use the [Display](std::fmt::Display) implementation of the event, given to the
+    /// plugin with [with_display](LogEventsPlugin::with_display).
+    Display,
+    /// Write the event in JSON through reflection. The event must be registered with
+    /// [register_type](App::register_type).
+    Json,
+    /// Use the formatter named after the given name, given to the plugin with
+    /// [with_formatter](LogEventsPlugin::with_formatter).
+    Custom(String),
 }
 
-/// The [Resource] that contains the settings used to log a particular [Event].
-#[derive(Resource, Deref, DerefMut)]
-pub struct LoggedEventSettings<E, C = ()> {
-    /// The settings describing how the [Event] will be logged. See [EventSettings].
-    #[deref]
-    pub settings: EventSettings,
-    _phantom: PhantomData<(E, C)>,
+impl LogFormat {
+    /// All the available formats, with an unnamed [Custom](LogFormat::Custom) one.
+    pub const ALL: [LogFormat; 5] = [
+        LogFormat::Compact,
+        LogFormat::Pretty,
+        LogFormat::Display,
+        LogFormat::Json,
+        LogFormat::Custom(String::new()),
+    ];
+
+    /// The format written as `name` in the settings files: one of the other formats if
+    /// `name` is one of theirs, or else a [Custom](LogFormat::Custom) one.
+    pub fn from_name(name: &str) -> Self {
+        LogFormat::ALL
+            .into_iter()
+            .find(|format| !matches!(format, LogFormat::Custom(_)) && format.to_string() == name)
+            .unwrap_or_else(|| LogFormat::Custom(name.to_string()))
+    }
 }
 
-impl<E, C> Default for LoggedEventSettings<E, C> {
-    fn default() -> Self {
+impl std::fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            LogFormat::Compact => "Compact",
+            LogFormat::Pretty => "Pretty",
+            LogFormat::Display => "Display",
+            LogFormat::Json => "Json",
+            LogFormat::Custom(name) => name,
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// A formatter given to [with_formatter](LogEventsPlugin::with_formatter), taking a value
+/// of the type it was given for.
+pub(crate) type Formatter = std::sync::Arc<dyn Fn(&dyn std::any::Any) -> String + Send + Sync>;
+
+/// Where the logs of an [Event] are written.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug, Reflect)]
+#[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
+pub enum LogOutput {
+    /// Log with [tracing](bevy::log::tracing), like the other logs of the program, or
+    /// with the [backend](LogEventsPlugin::with_backend) of the plugin.
+    #[default]
+    Tracing,
+    /// Only write the logs in the file of the event, see
+    /// [output_files](LogEventsPluginSettings::output_files), so a noisy event does not
+    /// fill the console.
+    File,
+    /// Log with [tracing](bevy::log::tracing) and write the logs in the file of the event.
+    Both,
+}
+
+/// What the logs of the events are written with, see
+/// [with_backend](LogEventsPlugin::with_backend).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum LogBackend {
+    /// The [tracing](bevy::log::tracing) macros, used by the
+    /// [LogPlugin](bevy::log::LogPlugin).
+    #[default]
+    Tracing,
+    /// The macros of the [log](https://docs.rs/log) crate, for the programs using a
+    /// logger of this crate instead of the [LogPlugin](bevy::log::LogPlugin).
+    ///
+    /// Requires the `log-compat` feature.
+    #[cfg(feature = "log-compat")]
+    Log,
+}
+
+/// A group of registrations, see [with_group](LogEvent::with_group).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LogGroupToken {
+    name: &'static str,
+    compiled: bool,
+}
+
+impl LogGroupToken {
+    /// The group `name`, whose registrations are made.
+    pub const fn new(name: &'static str) -> Self {
         Self {
-            settings: EventSettings::default(),
-            _phantom: PhantomData,
+            name,
+            compiled: true,
         }
     }
+
+    /// Only make the registrations of this group when `compiled` is true, which is usually
+    /// given by `cfg!`, so they are left out of the builds where it is false.
+    pub const fn compiled_if(mut self, compiled: bool) -> Self {
+        self.compiled = compiled;
+        self
+    }
+
+    /// The name of the group.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
 }
 
-/// The Trait implemented on [App] that helps you log [Event].
-///
-/// In Bevy you can interact with events in two ways :
-/// 1. using [EventWriter] to send events and [EventReader] to read them.
-/// 2. using [Commands] to trigger events and [Observer] to react to the [Trigger].
-///
-/// This trait offers two complementary ways of interacting with events, depending on how they were emitted :
-/// 1. [log_event](LogEvent::log_event), [add_and_log_event](LogEvent::add_and_log_event) and
-///    [add_and_log_state_scoped_event](LogEvent::add_and_log_state_scoped_event) will
-///    log [Event] sent with an [EventWriter].<br>
-///    These functions will not interact with triggered events.<br>
-///    These events will be logged with a delay at the end of each frame inside the [LogEventsSet].
-/// 2. [log_triggered](LogEvent::log_triggered) and [log_trigger](LogEvent::log_trigger)
-///    will log triggered [Event].<br>
-///    These functions will not interact with sent events.<br>
-///    These events will logged without a delay as soon as they are triggered.
+impl From<&'static str> for LogGroupToken {
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+/// How the names of the events are shown in the logs and the settings window. The names
+/// their settings are saved under are not changed, see
+/// [with_short_names](LogEventsPlugin::with_short_names) for that.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum NameStyle {
+    /// The full names, like `bevy_state::state::StateTransitionEvent<my_game::GameState>`.
+    #[default]
+    Full,
+    /// The modules shortened to their first letter, like
+    /// `b::s::StateTransitionEvent<m::GameState>`.
+    Abbreviated,
+    /// The names without their modules, like `StateTransitionEvent<GameState>`.
+    Short,
+}
+
+impl NameStyle {
+    /// All the available styles.
+    pub const ALL: [NameStyle; 3] = [NameStyle::Full, NameStyle::Abbreviated, NameStyle::Short];
+}
+
+impl std::fmt::Display for NameStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            NameStyle::Full => "Full",
+            NameStyle::Abbreviated => "Abbreviated",
+            NameStyle::Short => "Short",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// How the levels are written in the settings files. The levels can be read from any of
+/// these styles, whatever the case of their names.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum LevelStyle {
+    /// The names in uppercase, like `"DEBUG"`.
+    #[default]
+    Uppercase,
+    /// The names in lowercase, like `"debug"`.
+    Lowercase,
+    /// The numbers from 1 for `ERROR` to 5 for `TRACE`, like `4` for `DEBUG`.
+    Numeric,
+}
+
+impl LevelStyle {
+    /// All the available styles.
+    pub const ALL: [LevelStyle; 3] = [
+        LevelStyle::Uppercase,
+        LevelStyle::Lowercase,
+        LevelStyle::Numeric,
+    ];
+}
+
+/// Which of the [Event] sent with an [EventWriter] are logged by an event registered with
+/// [log_event](LogEvent::log_event) or the other functions logging sent events.
 ///
-/// As each one of these functions log events in independant situations you can use
-/// several of them at the same time for the same [Event] type, you will not get the same
-/// event log multiple times by doing that.
+/// Bevy keeps the events of the current and previous frames, so an event registered
+/// while the game is running would log the events sent before it was registered.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ReaderStart {
+    /// Log the events still kept by Bevy when the event is registered.
+    #[default]
+    Buffered,
+    /// Only log the events sent after the event is registered.
+    Now,
+}
+
+/// The settings shared by all the [Event] of a log channel.
 ///
-/// If an event `E` is registred with [log_event](LogEvent::log_event) and [log_triggered](LogEvent::log_triggered),
-/// it will share the same [LoggedEventSettings] resource for logging in both context.<br>
-/// In case of [log_trigger](LogEvent::log_trigger), you will get one [LoggedEventSettings] resource
-/// for each pair of event and component (`E`, `C`) you register.
-pub trait LogEvent {
-    /// This function add a system in the [Last] schedule inside the [LogEventsSet]
-    /// in charge of logging all the [Event] `E` sent with the corresponding [EventWriter].
-    fn log_event<E>(&mut self) -> &mut Self
-    where
-        E: Event + std::fmt::Debug;
+/// See [log_channel](LogEvent::log_channel) to learn how to put events in a channel.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
+pub struct ChannelSettings {
+    /// If false none of the [Event] of the channel will be logged.
+    pub enabled: bool,
+    #[cfg_attr(
+        feature = "enabled",
+        serde(
+            serialize_with = "serialize_level",
+            deserialize_with = "deserialize_level"
+        )
+    )]
+    /// The least severe [Level] that can be logged by the [Event] of the channel.
+    pub level: Level,
+}
 
-    /// Add and log an [Event] in one go. This is equivalent to :
+impl Default for ChannelSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            level: Level::TRACE,
+        }
+    }
+}
+
+impl<I: LogInstance> LogEventsPluginSettings<I> {
+    /// Whether the settings window and the overlay can be shown. They are not available
+    /// when there is no `WindowPlugin`, or no `EguiPlugin` and it was not added because of
+    /// [add_egui_plugin](LogEventsPlugin::add_egui_plugin).
+    pub fn window_available(&self) -> bool {
+        #[cfg(feature = "enabled")]
+        return self.window_available;
+        #[cfg(not(feature = "enabled"))]
+        false
+    }
+
+    /// The file the settings are loaded from and saved to on exit. It is the one in the
+    /// [fallback directory](LogEventsPlugin::with_fallback_dir) when the
+    /// [settings_path](LogEventsPlugin::settings_path) could not be written.
+    pub fn settings_path(&self) -> &std::path::Path {
+        #[cfg(feature = "enabled")]
+        return &self.saved_settings;
+        #[cfg(not(feature = "enabled"))]
+        std::path::Path::new("")
+    }
+
+    /// Show the widgets of `widget` in the row of each [Event] of the settings window,
+    /// after its settings. The widgets are shown in the order they were added.
+    ///
+    /// As an example :
     /// ```
-    /// app.add_event::<E>()
-    ///    .log_event::<E>()
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # mod my_tools {
+    /// #     pub fn replay(_name: &str) {}
+    /// # }
+    /// fn add_replay_button(mut settings: ResMut<LogEventsPluginSettings>) {
+    ///     settings.add_row_widget(|ui, name, _settings| {
+    ///         if ui.button("Replay").clicked() {
+    ///             my_tools::replay(name);
+    ///         }
+    ///     });
+    /// }
+    /// # App::new().add_systems(Startup, add_replay_button);
     /// ```
-    fn add_and_log_event<E>(&mut self) -> &mut Self
-    where
-        E: Event + std::fmt::Debug;
+    #[cfg(feature = "enabled")]
+    pub fn add_row_widget(
+        &mut self,
+        widget: impl Fn(&mut bevy_egui::egui::Ui, &str, &mut EventSettings) + Send + Sync + 'static,
+    ) {
+        self.row_widgets.push(Box::new(widget));
+    }
+}
+
+impl LogEventsPluginSettings {
+    /// The current settings of the plugin running in `world` and of all its registered
+    /// events, as they would be written in the settings file. This lets you compare them
+    /// with the ones of a file read with [settings_io::load] for example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [LogEventsPlugin] was not added to the [App] of `world`.
+    #[cfg(feature = "enabled")]
+    pub fn snapshot(world: &World) -> settings_io::LoggedEventsSettings {
+        systems::current_settings::<()>(world)
+    }
+}
+
+/// What is done when the settings are saved on exit while the settings file was changed
+/// by another instance of the program since it was loaded, see
+/// [with_save_policy](LogEventsPlugin::with_save_policy).
+///
+/// In both cases the file is replaced at once, so instances exiting at the same time can
+/// not leave it half written.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum SavePolicy {
+    /// The settings of the last instance to exit replace the ones in the file.
+    #[default]
+    Overwrite,
+    /// Only the settings changed by this instance replace the ones in the file, the others
+    /// keep the values written by the other instances.
+    Merge,
+}
+
+/// The format of a settings file, see [with_format](LogEventsPlugin::with_format).
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum SettingsFormat {
+    /// [RON](https://github.com/ron-rs/ron), the format used for the files with another
+    /// extension than the ones below.
+    #[default]
+    Ron,
+    /// JSON, for the files ending with `.json`.
+    Json,
+    /// TOML, for the files ending with `.toml`.
+    Toml,
+}
+
+impl SettingsFormat {
+    /// The format of the file at `path` according to its extension.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Self {
+        match path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => SettingsFormat::Json,
+            Some(extension) if extension.eq_ignore_ascii_case("toml") => SettingsFormat::Toml,
+            _ => SettingsFormat::Ron,
+        }
+    }
+}
+
+/// The colors used for the [Level] in the settings window.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
+pub enum LevelPalette {
+    /// The default colors.
+    #[default]
+    Default,
+    /// Colors that stay distinguishable with the common color vision deficiencies.
+    Colorblind,
+}
+
+/// The spacing of the widgets in the settings window.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum UiDensity {
+    /// The default spacing of egui.
+    #[default]
+    Compact,
+    /// More spacing and larger widgets, easier to hit on touch screens.
+    Comfortable,
+}
+
+/// A combination of search filters of the settings window, saved under a name so it can
+/// be recalled later.
+#[derive(Clone, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
+pub struct FilterPreset {
+    /// The text searched in the names of the events.
+    pub name_filter: String,
+    /// Whether the name search is case sensitive.
+    pub case_sensitive: bool,
+    /// Whether the name search is a regular expression.
+    pub use_regex: bool,
+    /// If set, only the events with this [enabled](EventSettings::enabled) value are shown.
+    pub enabled: Option<bool>,
+    #[cfg_attr(
+        feature = "enabled",
+        serde(
+            serialize_with = "serialize_optional_level",
+            deserialize_with = "deserialize_optional_level"
+        )
+    )]
+    /// If set, only the events logged at this [Level] are shown.
+    pub level: Option<Level>,
+    /// Whether the name search is a [FilterExpr](ui_model::FilterExpr), like
+    /// `name~"combat" && level>=DEBUG && enabled`.
+    #[cfg_attr(feature = "enabled", serde(default))]
+    pub use_expression: bool,
+}
+
+/// A sound played when an [Event] is logged, see
+/// [sounds](LogEventsPluginSettings::sounds).
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "enabled", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "enabled", serde(default))]
+pub struct SoundCue {
+    /// The asset path of the sound, like `"sounds/blip.ogg"`.
+    pub path: String,
+    /// The volume of the sound, `1.0` being its original volume.
+    pub volume: f32,
+    /// The minimum number of seconds between two plays of the sound, so an event logged
+    /// every frame does not play it every frame.
+    pub cooldown: f32,
+}
+
+impl Default for SoundCue {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            volume: 1.0,
+            cooldown: 1.0,
+        }
+    }
+}
+
+/// The settings used to configure the [LogEventsPlugin], one per [LogInstance].
+#[derive(Resource)]
+pub struct LogEventsPluginSettings<I = ()> {
+    /// If false no [Event] will be logged.
+    pub enabled: bool,
+    /// Whether to show or not the window to configure the [LoggedEventSettings].
+    pub show_window: bool,
+    /// Whether to show or not the overlay with the quick toggles of the
+    /// [pinned](LogEventsPluginSettings::pinned) events.
+    pub show_overlay: bool,
+    /// Whether to show or not the window with the last logs of the events, which needs the
+    /// plugin to be built [with_log_history](LogEventsPlugin::with_log_history).
+    pub show_log_viewer: bool,
+    /// The events shown in the overlay, by name. Events can be pinned from the settings
+    /// window, up to [MAX_PINNED] of them. These are saved with the rest of the settings.
+    pub pinned: BTreeSet<String>,
+    /// The favorite events, by name. They are starred in the settings window where they
+    /// are always shown first, whatever the search filters. These are saved with the rest
+    /// of the settings.
+    pub favorites: BTreeSet<String>,
+    /// The search filters saved by name from the settings window. These are saved with
+    /// the rest of the settings.
+    pub filter_presets: BTreeMap<String, FilterPreset>,
+    /// The least severe [Level] that can be logged. Every [Event] configured to be logged
+    /// at a more verbose [Level] will be ignored, whatever its own [EventSettings].
+    pub min_level: Level,
+    /// The [Entity] holding the egui context on which the settings window will be shown.
+    /// If [None], or if this entity has no egui context, the window will be shown on the
+    /// [PrimaryWindow](bevy::window::PrimaryWindow) or on the first egui context found
+    /// when there is no primary window.
+    pub window_context: Option<Entity>,
+    /// The settings of each log channel. See [log_channel](LogEvent::log_channel).
+    pub channels: BTreeMap<String, ChannelSettings>,
+    /// If set, a warning will be logged the first time the log of an [Event] is longer
+    /// than this number of bytes. This can help you find the events whose payload is
+    /// too big to be logged comfortably. Defaults to [None].
+    pub payload_size_warning: Option<usize>,
+    /// If true, append the size of the logged type and a hash of its [TypeId](std::any::TypeId)
+    /// to each log, like `[size=24B type=0x5f0e3a19c2d4b871]`. The logged type is the
+    /// [Component] for [log_trigger](LogEvent::log_trigger) and the [Event] otherwise.
+    /// Defaults to false.
+    pub show_type_info: bool,
+    /// The colors of the levels in the settings window, see [LevelPalette]. It is saved
+    /// with the rest of the settings.
+    pub level_palette: LevelPalette,
+    /// The spacing of the widgets in the settings window, see [UiDensity].
+    pub ui_density: UiDensity,
+    /// How the names of the events are shown, see [NameStyle].
+    pub name_style: NameStyle,
+    /// The groups whose events are never logged, whatever their settings are. See
+    /// [disable_group](LogEventsPlugin::disable_group).
+    pub disabled_groups: BTreeSet<String>,
+    /// The template used to write the logs of each event, by event name. Events without
+    /// a template are logged as `{name}: {payload}`, or `{name} on {entity}: {payload}`
+    /// when they target an [Entity].
+    ///
+    /// A template is a string where the placeholders `{name}`, `{level}`, `{entity}` and
+    /// `{payload}` will be replaced, like `"{name} [{level}] {entity} -> {payload}"`.
+    /// These templates are saved with the rest of the settings and can be edited in the
+    /// settings window.
+    pub templates: BTreeMap<String, String>,
+    /// A regular expression per event name. The logs of these events targeting an
+    /// [Entity] are only made when the [Name] of the entity matches it, like `"^Player"`.
+    /// The entities without a [Name] never match, and the invalid expressions are ignored.
+    ///
+    /// This applies to [log_triggered](LogEvent::log_triggered),
+    /// [log_trigger](LogEvent::log_trigger) and their variants. These filters are saved
+    /// with the rest of the settings and can be edited in the settings window.
+    pub entity_filters: BTreeMap<String, String>,
+    /// A [SoundCue] per event name, played each time the event is logged so the rare but
+    /// important events can be heard while playtesting. Only used with the `audio`
+    /// feature, which needs the `AudioPlugin` of Bevy and the features of the formats of
+    /// the sounds. These sounds are saved with the rest of the settings and can be edited
+    /// in the settings window.
+    pub sounds: BTreeMap<String, SoundCue>,
+    /// The file where the logs of each event are written when its
+    /// [output](EventSettings::output) is not [LogOutput::Tracing], by event name. The
+    /// events missing from it are written in `logs/` under their name, like
+    /// `logs/my_game__DamageEvent.log`. The files are appended to and flushed every second
+    /// and on [AppExit].
+    ///
+    /// These paths are saved with the rest of the settings and can be edited in the
+    /// settings window.
+    pub output_files: BTreeMap<String, PathBuf>,
+    /// Called with the name of the event and its log, before [log_breakpoint], when an
+    /// event with [break_on_log](EventSettings::break_on_log) is logged. Defaults to [None].
+    pub break_hook: Option<BreakHook>,
+    /// If set, the settings are kept synchronized through this file with the other
+    /// running instances of the program using the same path, which is handy when testing
+    /// several local clients. Changing a setting in one instance will change it in the
+    /// others too. The events missing from the file, like the ones another instance does
+    /// not register, keep their settings. Defaults to [None].
+    pub sync_path: Option<PathBuf>,
+    /// If set, the events logged at the [DEBUG](Level::DEBUG) and [TRACE](Level::TRACE)
+    /// levels stop being logged while the frames take longer than this on average, so
+    /// logging does not make a slow game even slower. A line is logged when it starts and
+    /// when the frame time recovers. Defaults to [None].
+    pub frame_budget: Option<Duration>,
+    /// The number of values kept for each entity by
+    /// [log_trigger_cached](LogEvent::log_trigger_cached), shown in the settings window
+    /// to see the last values a component had. Defaults to 0.
+    pub component_history: usize,
+    /// The lifecycle events of reflected components to log, like
+    /// `"OnInsert<my_game::Health>"`, so logging targets can be added by editing the
+    /// settings file without recompiling.
+    ///
+    /// They are read from the `watch` section of the settings file and registered at
+    /// [Startup], up to [MAX_WATCHED] of them. The components must be registered with
+    /// `app.register_type` and `#[reflect(Component)]`.
+    pub watch: Vec<String>,
+    /// The events renamed since the settings file was written, from their old name to
+    /// their new one, like `"my_game::DamageEvent": "my_game::combat::DamageEvent"`.
+    ///
+    /// They are read from the `renames` section of the settings file and applied when it
+    /// is loaded, so the settings saved under the old names are kept.
+    pub renames: BTreeMap<String, String>,
+    #[cfg(feature = "enabled")]
+    window_available: bool,
+    #[cfg(feature = "enabled")]
+    short_names: bool,
+    #[cfg(feature = "enabled")]
+    save_policy: SavePolicy,
+    #[cfg(feature = "enabled")]
+    reader_start: ReaderStart,
+    #[cfg(feature = "enabled")]
+    backend: LogBackend,
+    /// Whether the verbose events are not logged because of the
+    /// [frame_budget](Self::frame_budget).
+    #[cfg(feature = "enabled")]
+    over_budget: bool,
+    /// The content of the settings file when it was last loaded or saved on exit.
+    #[cfg(feature = "enabled")]
+    save_base: Option<settings_io::LoggedEventsSettings>,
+    /// The settings of the base file of [with_base](LogEventsPlugin::with_base), the
+    /// settings file only holding the differences from them.
+    #[cfg(feature = "enabled")]
+    base_settings: Option<settings_io::LoggedEventsSettings>,
+    /// The last settings loaded or saved, before their platform sections were applied.
+    #[cfg(feature = "enabled")]
+    file_settings: Option<settings_io::LoggedEventsSettings>,
+    #[cfg(feature = "enabled")]
+    fallback_path: Option<PathBuf>,
+    /// The format given to [with_format](LogEventsPlugin::with_format).
+    #[cfg(feature = "enabled")]
+    format: Option<SettingsFormat>,
+    /// The style given to [with_level_style](LogEventsPlugin::with_level_style).
+    #[cfg(feature = "enabled")]
+    level_style: LevelStyle,
+    #[cfg(feature = "enabled")]
+    saved_settings: PathBuf,
+    #[cfg(feature = "enabled")]
+    previous_settings: BTreeMap<String, EventSettings>,
+    #[cfg(feature = "enabled")]
+    row_widgets: Vec<RowWidget>,
+    /// Where the settings window is, its `open` field is not used.
+    #[cfg(feature = "enabled")]
+    window_layout: settings_io::WindowLayout,
+    /// The event soloed from the settings window, with the values of
+    /// [enabled](EventSettings::enabled) the events had before, restored when it is
+    /// un-soloed.
+    #[cfg(feature = "enabled")]
+    solo: Option<(String, BTreeMap<String, bool>)>,
+    /// Where the logs are kept until they are moved to the [LogHistory](history::LogHistory),
+    /// if the plugin was built [with_log_history](LogEventsPlugin::with_log_history).
+    #[cfg(feature = "enabled")]
+    history: Option<std::sync::Arc<history::PendingEntries>>,
+    #[cfg(feature = "enabled")]
+    pending_lines: log_files::PendingLines,
+    /// The time of the logs made during the frame.
+    #[cfg(feature = "enabled")]
+    frame_clock: systems::FrameClock,
+    #[cfg(all(feature = "enabled", feature = "notifications"))]
+    notifier: notifications::Notifier,
+    /// The formatters given to [with_formatter](LogEventsPlugin::with_formatter).
+    #[cfg(feature = "enabled")]
+    formatters: BTreeMap<(std::any::TypeId, String), Formatter>,
+    /// The types registered for reflection, used by [LogFormat::Json].
+    #[cfg(feature = "enabled")]
+    type_registry: AppTypeRegistry,
+    /// The expression read from [FILTER_ENV_VAR], applied to the events as they are
+    /// registered.
+    #[cfg(feature = "enabled")]
+    env_filter: Option<utils::FilterNode>,
+    _instance: PhantomData<fn() -> I>,
+}
+
+/// The [Resource] that contains the settings used to log a particular [Event].
+// The settings window accesses the settings through pointers to this resource,
+// `repr(C)` guarantees that `settings` is always its first field.
+#[derive(Resource, Deref, DerefMut)]
+#[repr(C)]
+pub struct LoggedEventSettings<E, C = ()> {
+    /// The settings describing how the [Event] will be logged. See [EventSettings].
+    #[deref]
+    pub settings: EventSettings,
+    name: String,
+    channel: Option<String>,
+    group: Option<&'static str>,
+    #[cfg(feature = "enabled")]
+    stats: std::sync::Arc<systems::EventStats>,
+    #[cfg(feature = "enabled")]
+    queue: Option<LogQueue<E>>,
+    /// The id of the first sent [Event] to log, see [ReaderStart].
+    #[cfg(feature = "enabled")]
+    first_id: usize,
+    _phantom: PhantomData<(E, C)>,
+}
+
+impl<E, C> LoggedEventSettings<E, C> {
+    /// The name the [Event] is logged with and its settings are saved under. It is empty
+    /// until the [Event] is registered, see [RegisterEventsSet].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The log channel the [Event] belongs to, if any. See [log_channel](LogEvent::log_channel).
+    pub fn channel(&self) -> Option<&str> {
+        self.channel.as_deref()
+    }
+
+    /// The group the [Event] belongs to, if any. See [with_group](LogEvent::with_group).
+    pub fn group(&self) -> Option<&'static str> {
+        self.group
+    }
+}
+
+impl<E, C> Default for LoggedEventSettings<E, C> {
+    fn default() -> Self {
+        Self {
+            settings: EventSettings::default(),
+            name: String::new(),
+            channel: None,
+            group: None,
+            #[cfg(feature = "enabled")]
+            stats: default(),
+            #[cfg(feature = "enabled")]
+            queue: None,
+            #[cfg(feature = "enabled")]
+            first_id: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E> LoggedEventSettings<E> {
+    /// Returns a [LogHandle] that can be used to log `E` from anywhere, including
+    /// from other threads. Returns [None] if `E` was not registered with
+    /// [log_event](LogEvent::log_event) or [log_event_with_formatter](LogEvent::log_event_with_formatter).
+    pub fn handle(&self) -> Option<LogHandle<E>> {
+        #[cfg(feature = "enabled")]
+        {
+            self.queue.as_ref().map(|queue| LogHandle {
+                sender: queue.sender.clone(),
+                dropped: queue.dropped.clone(),
+            })
+        }
+        #[cfg(not(feature = "enabled"))]
+        None
+    }
+}
+
+/// The number of events that can wait in the queue of a [LogHandle] before new ones
+/// are dropped.
+pub const LOG_HANDLE_CAPACITY: usize = 4096;
+
+/// A cheap and cloneable handle used to log an [Event] `E` from contexts without access
+/// to the [World], like parallel systems or async tasks.
+///
+/// The events sent through a [LogHandle] are queued without locking and logged at the
+/// end of the frame inside the [LogEventsSet], after the events read from the
+/// [EventReader]. If more than [LOG_HANDLE_CAPACITY] events are waiting, the new ones
+/// are dropped.
+///
+/// The events sent while the plugin is disabled are dropped at the end of the frame, and a
+/// warning is logged when some were dropped because the queue was full.
+///
+/// You can get a [LogHandle] from the [LoggedEventSettings] of your event with
+/// [handle](LoggedEventSettings::handle).
+pub struct LogHandle<E> {
+    #[cfg(feature = "enabled")]
+    sender: Sender<E>,
+    #[cfg(feature = "enabled")]
+    dropped: std::sync::Arc<AtomicU64>,
+    #[cfg(not(feature = "enabled"))]
+    _phantom: PhantomData<E>,
+}
+
+/// The queue of the events sent through the [LogHandle]s of an [Event].
+#[cfg(feature = "enabled")]
+struct LogQueue<E> {
+    sender: Sender<E>,
+    receiver: Receiver<E>,
+    /// The number of events dropped because the queue was full, since it was last
+    /// reported.
+    dropped: std::sync::Arc<AtomicU64>,
+}
+
+impl<E> Clone for LogHandle<E> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "enabled")]
+            sender: self.sender.clone(),
+            #[cfg(feature = "enabled")]
+            dropped: self.dropped.clone(),
+            #[cfg(not(feature = "enabled"))]
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// The [Resource] deciding how an [Entity] is written in the logs.
+///
+/// By default an entity is written as `Name(entity)` if it has a [Name] and as the bare
+/// [Entity] otherwise. You can insert your own [EntityLabeler] to change that for every
+/// log mentioning an entity, for example to add a network id :
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_log_events::prelude::*;
+/// # #[derive(Component)]
+/// # struct NetworkId(u64);
+/// # let mut app = App::new();
+/// app.insert_resource(EntityLabeler::new(|entity| match entity.get::<NetworkId>() {
+///     Some(id) => format!("{}#{}", entity.id(), id.0),
+///     None => format!("{}", entity.id()),
+/// }));
+/// ```
+/// Entities that can not be found anymore, for example because they were despawned,
+/// are always written as the bare [Entity].
+#[derive(Resource)]
+pub struct EntityLabeler(Box<dyn Fn(EntityRef) -> String + Send + Sync>);
+
+impl EntityLabeler {
+    /// Create a new [EntityLabeler] using `labeler` to write the entities.
+    pub fn new(labeler: impl Fn(EntityRef) -> String + Send + Sync + 'static) -> Self {
+        Self(Box::new(labeler))
+    }
+
+    /// Returns the label of `entity`.
+    pub fn label(&self, entity: EntityRef) -> String {
+        (self.0)(entity)
+    }
+
+    /// Write `entity` with this [EntityLabeler], looking it up in `entities`.
+    pub fn display<'a, S>(&'a self, entities: &'a S, entity: Entity) -> DisplayEntity<'a, S>
+    where
+        S: EntitySource + ?Sized,
+    {
+        DisplayEntity {
+            labeler: Some(self),
+            entities,
+            entity,
+        }
+    }
+}
+
+impl Default for EntityLabeler {
+    fn default() -> Self {
+        Self::new(default_label)
+    }
+}
+
+fn default_label(entity: EntityRef) -> String {
+    match entity.get::<Name>() {
+        Some(name) => format!("{}({})", name, entity.id()),
+        None => format!("{}", entity.id()),
+    }
+}
+
+/// Where the entities written by a [DisplayEntity] are looked up: a [World] or a [Query]
+/// of [EntityRef].
+pub trait EntitySource {
+    /// Returns `entity`, or [None] if it can not be found.
+    fn entity_ref(&self, entity: Entity) -> Option<EntityRef<'_>>;
+
+    /// Returns the [EntityLabeler] to use when none is given, the default one if [None].
+    fn labeler(&self) -> Option<&EntityLabeler> {
+        None
+    }
+}
+
+impl EntitySource for World {
+    fn entity_ref(&self, entity: Entity) -> Option<EntityRef<'_>> {
+        self.get_entity(entity).ok()
+    }
+
+    fn labeler(&self) -> Option<&EntityLabeler> {
+        self.get_resource::<EntityLabeler>()
+    }
+}
+
+impl<F: QueryFilter> EntitySource for Query<'_, '_, EntityRef<'_>, F> {
+    fn entity_ref(&self, entity: Entity) -> Option<EntityRef<'_>> {
+        self.get(entity).ok()
+    }
+}
+
+/// Writes an [Entity] the same way as the logs of this crate, see [EntityLabeler].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_log_events::prelude::*;
+/// # #[derive(Resource)]
+/// # struct Target(Entity);
+/// fn show_target(target: Res<Target>, entities: Query<EntityRef>) {
+///     info!("Targeting {}", DisplayEntity::new(&entities, target.0));
+/// }
+/// # App::new().add_systems(Update, show_target);
+/// ```
+/// With a [World] its [EntityLabeler] is used, with a [Query] use
+/// [EntityLabeler::display] to get the one you inserted.
+pub struct DisplayEntity<'a, S: EntitySource + ?Sized> {
+    labeler: Option<&'a EntityLabeler>,
+    entities: &'a S,
+    entity: Entity,
+}
+
+impl<'a, S: EntitySource + ?Sized> DisplayEntity<'a, S> {
+    /// Write `entity`, looking it up in `entities`.
+    pub fn new(entities: &'a S, entity: Entity) -> Self {
+        Self {
+            labeler: None,
+            entities,
+            entity,
+        }
+    }
+}
+
+impl<S: EntitySource + ?Sized> std::fmt::Display for DisplayEntity<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(entity) = self.entities.entity_ref(self.entity) else {
+            return write!(f, "{}", self.entity);
+        };
+        let label = match self.labeler.or_else(|| self.entities.labeler()) {
+            Some(labeler) => labeler.label(entity),
+            None => default_label(entity),
+        };
+        f.write_str(&label)
+    }
+}
+
+/// Returns `entity` written the same way as the logs of this crate, see [DisplayEntity].
+pub fn display_entity<S: EntitySource + ?Sized>(entities: &S, entity: Entity) -> String {
+    DisplayEntity::new(entities, entity).to_string()
+}
+
+impl<E> LogHandle<E> {
+    /// Queue `event` to be logged at the end of the frame.
+    pub fn log(&self, event: E) {
+        #[cfg(feature = "enabled")]
+        if self.sender.try_send(event).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        #[cfg(not(feature = "enabled"))]
+        let _ = event;
+    }
+}
+
+#[cfg(feature = "enabled")]
+fn new_queued_settings<E: Event, I: LogInstance>(world: &World) -> LoggedEventSettings<E> {
+    let start = world
+        .get_resource::<CurrentReaderStart>()
+        .map(|start| start.0)
+        .or_else(|| {
+            world
+                .get_resource::<LogEventsPluginSettings<I>>()
+                .map(|settings| settings.reader_start)
+        })
+        .unwrap_or_default();
+    let first_id = match (start, world.get_resource::<Events<E>>()) {
+        (ReaderStart::Now, Some(events)) => events.oldest_event_count() + events.len(),
+        _ => 0,
+    };
+    LoggedEventSettings {
+        queue: Some({
+            let (sender, receiver) = bounded(LOG_HANDLE_CAPACITY);
+            LogQueue {
+                sender,
+                receiver,
+                dropped: default(),
+            }
+        }),
+        first_id,
+        ..new_logged_settings(world)
+    }
+}
+
+#[cfg(feature = "enabled")]
+pub(crate) fn new_logged_settings<E, C>(world: &World) -> LoggedEventSettings<E, C> {
+    LoggedEventSettings {
+        channel: world
+            .get_resource::<CurrentLogChannel>()
+            .map(|channel| channel.0.clone()),
+        group: world.get_resource::<CurrentLogGroup>().map(|group| group.0),
+        ..default()
+    }
+}
+
+/// The Trait implemented on [App] that helps you log [Event].
+///
+/// In Bevy you can interact with events in two ways :
+/// 1. using [EventWriter] to send events and [EventReader] to read them.
+/// 2. using [Commands] to trigger events and [Observer] to react to the [Trigger].
+///
+/// This trait offers two complementary ways of interacting with events, depending on how they were emitted :
+/// 1. [log_event](LogEvent::log_event), [add_and_log_event](LogEvent::add_and_log_event) and
+///    [add_and_log_state_scoped_event](LogEvent::add_and_log_state_scoped_event) will
+///    log [Event] sent with an [EventWriter].<br>
+///    These functions will not interact with triggered events.<br>
+///    These events will be logged with a delay at the end of each frame inside the [LogEventsSet].
+/// 2. [log_triggered](LogEvent::log_triggered) and [log_trigger](LogEvent::log_trigger)
+///    will log triggered [Event].<br>
+///    These functions will not interact with sent events.<br>
+///    These events will logged without a delay as soon as they are triggered.
+///
+/// As each one of these functions log events in independant situations you can use
+/// several of them at the same time for the same [Event] type, you will not get the same
+/// event log multiple times by doing that.
+///
+/// If an event `E` is registred with [log_event](LogEvent::log_event) and [log_triggered](LogEvent::log_triggered),
+/// it will share the same [LoggedEventSettings] resource for logging in both context.<br>
+/// In case of [log_trigger](LogEvent::log_trigger), you will get one [LoggedEventSettings] resource
+/// for each pair of event and component (`E`, `C`) you register.
+///
+/// Events can also be grouped in channels with [log_channel](LogEvent::log_channel).
+pub trait LogEvent {
+    /// This function add a system inside the [LogEventsSet]
+    /// in charge of logging all the [Event] `E` sent with the corresponding [EventWriter].
+    fn log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug;
+
+    /// Works like [log_event](LogEvent::log_event) but uses `formatter` to turn the [Event]
+    /// into the text that will be logged instead of its [Debug] implementation.
+    ///
+    /// This lets you log events that do not implement [Debug], like events coming from
+    /// external crates. As the formatting is up to you, the
+    /// [format](EventSettings::format) setting of these events will have no effect.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event)]
+    /// # struct ExternalEvent(u32);
+    /// # impl ExternalEvent {
+    /// #     fn id(&self) -> u32 {
+    /// #         self.0
+    /// #     }
+    /// # }
+    /// # let mut app = App::new();
+    /// # app.add_event::<ExternalEvent>();
+    /// app.log_event_with_formatter::<ExternalEvent>(|event| format!("id = {}", event.id()));
+    /// ```
+    fn log_event_with_formatter<E>(
+        &mut self,
+        formatter: impl Fn(&E) -> String + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        E: Event;
+
+    /// Works like [log_event](LogEvent::log_event) but formats the [Event] through its
+    /// [Reflect] implementation instead of [Debug], to log the events of external crates
+    /// that only implement [Reflect] without wrapping them.
+    fn log_event_reflect<E>(&mut self) -> &mut Self
+    where
+        E: Event + Reflect;
+
+    /// Works like [log_event](LogEvent::log_event) but logs the [Event] and saves its
+    /// settings under `name` instead of its type name, so its saved settings are kept
+    /// when the type is moved or renamed.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct DamageEvent;
+    /// # let mut app = App::new();
+    /// # app.add_event::<DamageEvent>();
+    /// app.log_event_as::<DamageEvent>("DamageEvent");
+    /// ```
+    fn log_event_as<E>(&mut self, name: impl Into<String>) -> &mut Self
+    where
+        E: Event + std::fmt::Debug;
+
+    /// Add and log an [Event] in one go. This is equivalent to :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct E;
+    /// # let mut app = App::new();
+    /// app.add_event::<E>()
+    ///    .log_event::<E>();
+    /// ```
+    fn add_and_log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug;
 
     /// Add and log a state scoped [Event] in one go. This is equivalent to :
     /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct E;
+    /// # #[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Playing,
+    /// # }
+    /// # let mut app = App::new();
+    /// # app.add_plugins(bevy::state::app::StatesPlugin).init_state::<GameState>();
+    /// # let state = GameState::Playing;
     /// app.add_state_scoped_event::<E>(state)
-    ///    .log_event::<E>()
+    ///    .log_event::<E>();
     /// ```
     /// See [add_state_scoped_event](StateScopedEventsAppExt::add_state_scoped_event) for details.
     fn add_and_log_state_scoped_event<E>(&mut self, state: impl FreelyMutableState) -> &mut Self
@@ -209,8 +1698,19 @@ pub trait LogEvent {
     /// If in addition the [Trigger] targets an [Entity], it will also log the entity
     /// id and its [Name] if any.
     ///
+    /// The [Observer] only exists while the [Event] can be logged, it is spawned when
+    /// its [LoggedEventSettings] are enabled and despawned when they are disabled.
+    ///
     /// As an example:
     /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// # let mut world = World::new();
+    /// # let entity = world.spawn_empty().id();
+    /// # let mut commands = world.commands();
     /// // If you log triggered events MyEvent
     /// app.log_triggered::<MyEvent>();
     ///
@@ -233,8 +1733,21 @@ pub trait LogEvent {
     /// This was designed with [OnAdd], [OnInsert], [OnRemove] and [OnReplace] in mind but you can use
     /// it with your own events too.
     ///
+    /// Like with [log_triggered](LogEvent::log_triggered), the [Observer] only exists while
+    /// its [LoggedEventSettings] are enabled.
+    ///
     /// As an example :
     /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct MyEvent;
+    /// # #[derive(Component, Debug)]
+    /// # struct MyComponent;
+    /// # let mut app = App::new();
+    /// # let mut world = World::new();
+    /// # let entity = world.spawn_empty().id();
+    /// # let mut commands = world.commands();
     /// // If you log MyComponent when MyEvent is triggered
     /// app.log_trigger::<MyEvent, MyComponent>();
     ///
@@ -252,19 +1765,320 @@ pub trait LogEvent {
     where
         E: Event,
         C: Component + std::fmt::Debug;
-}
 
-impl LogEvent for App {
-    fn log_event<E>(&mut self) -> &mut Self
+    /// Works like [log_trigger](LogEvent::log_trigger) but also keeps a clone of the last
+    /// value of the [Component] `C` inserted on each [Entity].
+    ///
+    /// When the [Component] can not be found anymore on the targeted [Entity], for example
+    /// because it was already removed or the entity despawned, the cached value will be
+    /// logged instead so you can still see the last value the component had.
+    ///
+    /// This shares the same [LoggedEventSettings] resource as [log_trigger](LogEvent::log_trigger),
+    /// so you should only use one of them for each pair of event and component (`E`, `C`).
+    fn log_trigger_cached<E, C>(&mut self) -> &mut Self
     where
-        E: Event + std::fmt::Debug,
-    {
-        #[cfg(feature = "enabled")]
-        {
+        E: Event,
+        C: Component + std::fmt::Debug + Clone;
+
+    /// Calls [log_trigger](LogEvent::log_trigger) for each [Component] of the tuple `B`.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Component, Debug)]
+    /// # struct Health;
+    /// # #[derive(Component, Debug)]
+    /// # struct Armor;
+    /// # #[derive(Component, Debug)]
+    /// # struct Weapon;
+    /// # let mut app = App::new();
+    /// app.log_trigger_many::<OnAdd, (Health, Armor, Weapon)>();
+    /// ```
+    fn log_trigger_many<E, B>(&mut self) -> &mut Self
+    where
+        E: Event,
+        B: LoggedComponents;
+
+    /// Every [Event] registered inside `f` with the other functions of this trait will
+    /// belong to the log channel `channel`.
+    ///
+    /// Each channel can be enabled or disabled as a whole and given a minimum [Level]
+    /// with its [ChannelSettings], which are stored in the [LogEventsPluginSettings]
+    /// and saved with the rest of the settings.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct PathFound;
+    /// # #[derive(Event, Debug)]
+    /// # struct TargetAcquired;
+    /// # let mut app = App::new();
+    /// # app.add_event::<PathFound>();
+    /// app.log_channel("AI", |app| {
+    ///     app.log_event::<PathFound>()
+    ///         .log_triggered::<TargetAcquired>();
+    /// });
+    /// ```
+    fn log_channel(&mut self, channel: impl Into<String>, f: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Every [Event] registered inside `f` with the other functions of this trait will
+    /// belong to the group `group`. Unlike channels, groups are not meant to be changed at
+    /// runtime but by the configuration of the build.
+    ///
+    /// `f` is not called at all when `group` is not [compiled](LogGroupToken::compiled_if),
+    /// and the events of a group can be force-disabled with
+    /// [disable_group](LogEventsPlugin::disable_group).
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct PacketReceived;
+    /// # #[derive(Event, Debug)]
+    /// # struct PacketSent;
+    /// # let mut app = App::new();
+    /// # app.add_event::<PacketReceived>().add_event::<PacketSent>();
+    /// const NETCODE: LogGroupToken =
+    ///     LogGroupToken::new("netcode").compiled_if(cfg!(debug_assertions));
+    ///
+    /// app.with_group(NETCODE, |app| {
+    ///     app.log_event::<PacketReceived>()
+    ///         .log_event::<PacketSent>();
+    /// });
+    /// ```
+    fn with_group(
+        &mut self,
+        group: impl Into<LogGroupToken>,
+        f: impl FnOnce(&mut Self),
+    ) -> &mut Self;
+
+    /// Every [Event] registered inside `f` with the functions logging sent events, like
+    /// [log_event](LogEvent::log_event), will start reading the events from `start`
+    /// instead of the one given to
+    /// [with_reader_start](LogEventsPlugin::with_reader_start).
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_event::<CursorMoved>();
+    /// app.with_reader_start(ReaderStart::Now, |app| {
+    ///     app.log_event::<CursorMoved>();
+    /// });
+    /// ```
+    fn with_reader_start(&mut self, start: ReaderStart, f: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Log the gamepad connections, disconnections and inputs in one go.
+    ///
+    /// This registers [GamepadConnectionEvent](bevy::input::gamepad::GamepadConnectionEvent),
+    /// [GamepadButtonStateChangedEvent](bevy::input::gamepad::GamepadButtonStateChangedEvent) and
+    /// [GamepadAxisChangedEvent](bevy::input::gamepad::GamepadAxisChangedEvent) like [log_event](LogEvent::log_event) would, but each log
+    /// describes the gamepad with its [Name], entity, vendor id and product id instead
+    /// of only its [Entity].
+    ///
+    /// Each one of these events gets its own [LoggedEventSettings], so you can for example
+    /// disable the axis changes which are quite verbose.
+    fn log_gamepad_events(&mut self) -> &mut Self;
+
+    /// Log the interactions with the `bevy_ui` nodes in the channel `"UI"`.
+    ///
+    /// This logs the changes of [Interaction](bevy::ui::Interaction), the presses of
+    /// [Button](bevy::ui::widget::Button) and when a button is added or removed, with lines
+    /// like `Interaction changed to Pressed on StartButton(12v0)`.
+    ///
+    /// Requires the `ui` feature.
+    #[cfg(feature = "ui")]
+    fn log_ui_interactions(&mut self) -> &mut Self;
+
+    /// Log the [AppExit](bevy::app::AppExit) events and, with the `window` feature, the
+    /// [AppLifecycle](bevy::window::AppLifecycle) events like
+    /// [log_event](LogEvent::log_event) would.
+    fn log_bevy_app_events(&mut self) -> &mut Self;
+
+    /// Log the events of the windows like [log_event](LogEvent::log_event) would: their
+    /// creation, closing, resizing, moves, focus, scale factor and theme changes, and the
+    /// cursor, drag and drop and IME events.
+    ///
+    /// Requires the `window` feature.
+    #[cfg(feature = "window")]
+    fn log_bevy_window_events(&mut self) -> &mut Self;
+
+    /// Log the [AssetEvent](bevy::asset::AssetEvent) and
+    /// [AssetLoadFailedEvent](bevy::asset::AssetLoadFailedEvent) of the [Asset](bevy::asset::Asset)
+    /// `A` like [log_event](LogEvent::log_event) would, the failed loads being logged with
+    /// the path of the asset and the error.
+    ///
+    /// Requires the `asset` feature.
+    #[cfg(feature = "asset")]
+    fn log_bevy_asset_events<A: bevy::asset::Asset>(&mut self) -> &mut Self;
+
+    /// Log every type annotated with `#[derive(LoggedEvent)]` like
+    /// [log_event](LogEvent::log_event) would, without a call for each type.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::{prelude::*, LoggedEvent};
+    /// #[derive(Event, Debug, LoggedEvent)]
+    /// struct MyEvent;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_event::<MyEvent>();
+    /// app.log_all_derived();
+    /// ```
+    ///
+    /// Requires the `derive` feature.
+    #[cfg(feature = "derive")]
+    fn log_all_derived(&mut self) -> &mut Self;
+
+    /// Every [Event] registered inside `f` with the functions of this trait will be logged
+    /// by the instance `I` of the [LogEventsPlugin], added with
+    /// [new_instance](LogEventsPlugin::new_instance), instead of the default one. Its
+    /// settings are saved in the settings file of this instance and shown in its own
+    /// settings window.
+    ///
+    /// As an example :
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_log_events::prelude::*;
+    /// # #[derive(Event, Debug)]
+    /// # struct PacketReceived;
+    /// # #[derive(Event, Debug)]
+    /// # struct PacketSent;
+    /// # struct NetworkLogs;
+    /// # let mut app = App::new();
+    /// # app.add_event::<PacketReceived>().add_event::<PacketSent>();
+    /// app.log_instance::<NetworkLogs>(|app| {
+    ///     app.log_event::<PacketReceived>()
+    ///         .log_event::<PacketSent>();
+    /// });
+    /// ```
+    ///
+    /// An [Event] can only be logged by one instance. The events of
+    /// [log_all_derived](LogEvent::log_all_derived) can only be logged by the default one.
+    fn log_instance<I: LogInstance>(&mut self, f: impl FnOnce(&mut LogInstanceApp<I>))
+        -> &mut Self;
+}
+
+/// The [App] given to the function of [log_instance](LogEvent::log_instance), whose
+/// [LogEvent] functions register the events with the instance `I` of the
+/// [LogEventsPlugin]. The other functions of the [App] can be used through it too.
+pub struct LogInstanceApp<'a, I> {
+    app: &'a mut App,
+    _instance: PhantomData<fn() -> I>,
+}
+
+impl<'a, I> LogInstanceApp<'a, I> {
+    fn new(app: &'a mut App) -> Self {
+        Self {
+            app,
+            _instance: PhantomData,
+        }
+    }
+}
+
+impl<I> std::ops::Deref for LogInstanceApp<'_, I> {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        self.app
+    }
+}
+
+impl<I> std::ops::DerefMut for LogInstanceApp<'_, I> {
+    fn deref_mut(&mut self) -> &mut App {
+        self.app
+    }
+}
+
+/// Run `f` with `value` inserted in the [World] of `app`, putting back the previous value
+/// of the resource after.
+#[cfg(feature = "enabled")]
+fn scoped<A, R>(app: &mut A, value: R, f: impl FnOnce(&mut A))
+where
+    A: std::ops::DerefMut<Target = App>,
+    R: Resource,
+{
+    let previous = app.world_mut().remove_resource::<R>();
+    app.insert_resource(value);
+    f(app);
+    app.world_mut().remove_resource::<R>();
+    if let Some(previous) = previous {
+        app.insert_resource(previous);
+    }
+}
+
+impl<I: LogInstance> LogEvent for LogInstanceApp<'_, I> {
+    fn log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        #[cfg(feature = "enabled")]
+        {
+            if !self.world().contains_resource::<LoggedEventSettings<E>>() {
+                let settings = new_queued_settings::<E, I>(self.world());
+                self.insert_resource(settings);
+                add_to_log_schedule::<I, _>(self, log_event::<E, I>.in_set(InstanceSet::of::<I>()));
+                queue_registration::<I>(self, type_name::<E>(), register_sent_event::<E, I>);
+            } else {
+                warn!(
+                    "You tried to use log_event twice for the event \"{}\"",
+                    type_name::<E>()
+                );
+            }
+        }
+        self
+    }
+
+    fn log_event_with_formatter<E>(
+        &mut self,
+        formatter: impl Fn(&E) -> String + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        E: Event,
+    {
+        #[cfg(feature = "enabled")]
+        {
+            if !self.world().contains_resource::<LoggedEventSettings<E>>() {
+                let settings = new_queued_settings::<E, I>(self.world());
+                self.insert_resource(settings)
+                    .insert_resource(EventFormatter::<E>(Box::new(formatter)));
+                add_to_log_schedule::<I, _>(
+                    self,
+                    log_formatted_event::<E, I>.in_set(InstanceSet::of::<I>()),
+                );
+                queue_registration::<I>(self, type_name::<E>(), register_sent_event::<E, I>);
+            } else {
+                warn!(
+                    "You tried to use log_event twice for the event \"{}\"",
+                    type_name::<E>()
+                );
+            }
+        }
+        #[cfg(not(feature = "enabled"))]
+        let _ = formatter;
+        self
+    }
+
+    fn log_event_reflect<E>(&mut self) -> &mut Self
+    where
+        E: Event + Reflect,
+    {
+        #[cfg(feature = "enabled")]
+        {
             if !self.world().contains_resource::<LoggedEventSettings<E>>() {
-                self.insert_resource(LoggedEventSettings::<E>::default())
-                    .add_systems(Startup, register_event::<E>.in_set(RegisterEventsSet))
-                    .add_systems(Last, log_event::<E>.in_set(LogEventsSet));
+                let settings = new_queued_settings::<E, I>(self.world());
+                self.insert_resource(settings);
+                add_to_log_schedule::<I, _>(
+                    self,
+                    log_reflected_event::<E, I>.in_set(InstanceSet::of::<I>()),
+                );
+                queue_registration::<I>(self, type_name::<E>(), register_sent_event::<E, I>);
             } else {
                 warn!(
                     "You tried to use log_event twice for the event \"{}\"",
@@ -275,18 +2089,48 @@ impl LogEvent for App {
         self
     }
 
+    fn log_event_as<E>(&mut self, name: impl Into<String>) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        #[cfg(feature = "enabled")]
+        {
+            let registered = self.world().contains_resource::<LoggedEventSettings<E>>();
+            self.log_event::<E>();
+            if !registered {
+                self.world_mut()
+                    .resource_mut::<LoggedEventSettings<E>>()
+                    .name = name.into();
+            }
+        }
+        #[cfg(not(feature = "enabled"))]
+        let _ = name;
+        self
+    }
+
     fn add_and_log_event<E>(&mut self) -> &mut Self
     where
         E: Event + std::fmt::Debug,
     {
-        self.add_event::<E>().log_event::<E>()
+        self.add_event::<E>();
+        self.log_event::<E>()
     }
 
     fn add_and_log_state_scoped_event<E>(&mut self, state: impl FreelyMutableState) -> &mut Self
     where
         E: Event + std::fmt::Debug,
     {
-        self.add_state_scoped_event::<E>(state).log_event::<E>()
+        self.add_state_scoped_event::<E>(state);
+        self.log_event::<E>()
+    }
+
+    fn log_trigger_many<E, B>(&mut self) -> &mut Self
+    where
+        E: Event,
+        B: LoggedComponents,
+    {
+        B::log_trigger_each::<E>(self);
+        self
     }
 
     fn log_triggered<E>(&mut self) -> &mut Self
@@ -296,13 +2140,14 @@ impl LogEvent for App {
         #[cfg(feature = "enabled")]
         {
             if !self.world().contains_resource::<LoggedEventSettings<E>>() {
-                let observer = Observer::new(log_triggered::<E>);
-                self.world_mut().spawn((
-                    observer,
-                    Name::new(format!("LogTrigger<{}>", type_name::<E>())),
-                ));
-                self.insert_resource(LoggedEventSettings::<E>::default())
-                    .add_systems(Startup, register_event::<E>.in_set(RegisterEventsSet));
+                add_log_observer::<I, _, _, _>(
+                    self,
+                    log_triggered::<E, I>,
+                    format!("LogTrigger<{}>", type_name::<E>()),
+                );
+                let settings = new_logged_settings::<E, ()>(self.world());
+                self.insert_resource(settings);
+                queue_registration::<I>(self, type_name::<E>(), register_event::<E, I>);
             } else {
                 warn!(
                     "You tried to use log_triggered twice for the event \"{}\"",
@@ -324,16 +2169,22 @@ impl LogEvent for App {
                 .world()
                 .contains_resource::<LoggedEventSettings<E, C>>()
             {
-                let observer = Observer::new(log_component::<E, C>);
-                self.world_mut().spawn((
-                    observer,
-                    Name::new(format!("Log{}", trigger_name::<E, C>())),
-                ));
-                self.insert_resource(LoggedEventSettings::<E, C>::default())
-                    .add_systems(
-                        Startup,
-                        register_component::<E, C>.in_set(RegisterEventsSet),
-                    );
+                add_log_observer::<I, _, _, _>(
+                    self,
+                    log_component::<E, C, I>,
+                    format!("Log{}", trigger_name::<E, C>()),
+                );
+                let settings = new_logged_settings::<E, C>(self.world());
+                self.insert_resource(settings);
+                add_to_log_schedule::<I, _>(
+                    self,
+                    flush_burst::<E, C, I>.in_set(InstanceSet::of::<I>()),
+                );
+                queue_registration::<I>(
+                    self,
+                    trigger_name::<E, C>(),
+                    register_component::<E, C, I>,
+                );
             } else {
                 warn!(
                     "You tried to use log_trigger twice for the trigger \"{}\"",
@@ -343,4 +2194,861 @@ impl LogEvent for App {
         }
         self
     }
+
+    fn log_trigger_cached<E, C>(&mut self) -> &mut Self
+    where
+        E: Event,
+        C: Component + std::fmt::Debug + Clone,
+    {
+        #[cfg(feature = "enabled")]
+        {
+            if !self
+                .world()
+                .contains_resource::<LoggedEventSettings<E, C>>()
+            {
+                if !self.world().contains_resource::<LastComponentValues<C>>() {
+                    let observer = Observer::new(cache_component::<C, I>);
+                    self.world_mut().spawn((
+                        observer,
+                        Name::new(format!("CacheLast<{}>", type_name::<C>())),
+                    ));
+                    self.init_resource::<LastComponentValues<C>>();
+                    add_to_log_schedule::<I, _>(self, prune_component_cache::<C>);
+                }
+                add_log_observer::<I, _, _, _>(
+                    self,
+                    log_cached_component::<E, C, I>,
+                    format!("Log{}", trigger_name::<E, C>()),
+                );
+                let settings = new_logged_settings::<E, C>(self.world());
+                self.insert_resource(settings);
+                add_to_log_schedule::<I, _>(
+                    self,
+                    flush_burst::<E, C, I>.in_set(InstanceSet::of::<I>()),
+                );
+                queue_registration::<I>(
+                    self,
+                    trigger_name::<E, C>(),
+                    register_component::<E, C, I>,
+                );
+            } else {
+                warn!(
+                    "You tried to use log_trigger twice for the trigger \"{}\"",
+                    trigger_name::<E, C>()
+                );
+            }
+        }
+        self
+    }
+
+    fn log_channel(&mut self, channel: impl Into<String>, f: impl FnOnce(&mut Self)) -> &mut Self {
+        #[cfg(feature = "enabled")]
+        scoped(self, CurrentLogChannel(channel.into()), f);
+        #[cfg(not(feature = "enabled"))]
+        {
+            let _ = channel;
+            f(self);
+        }
+        self
+    }
+
+    fn with_group(
+        &mut self,
+        group: impl Into<LogGroupToken>,
+        f: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        let group = group.into();
+        if !group.compiled {
+            return self;
+        }
+        #[cfg(feature = "enabled")]
+        scoped(self, CurrentLogGroup(group.name), f);
+        #[cfg(not(feature = "enabled"))]
+        f(self);
+        self
+    }
+
+    fn with_reader_start(&mut self, start: ReaderStart, f: impl FnOnce(&mut Self)) -> &mut Self {
+        #[cfg(feature = "enabled")]
+        scoped(self, CurrentReaderStart(start), f);
+        #[cfg(not(feature = "enabled"))]
+        {
+            let _ = start;
+            f(self);
+        }
+        self
+    }
+
+    fn log_gamepad_events(&mut self) -> &mut Self {
+        #[cfg(feature = "enabled")]
+        {
+            log_gamepad_event_type::<GamepadConnectionEvent, I>(self);
+            log_gamepad_event_type::<GamepadButtonStateChangedEvent, I>(self);
+            log_gamepad_event_type::<GamepadAxisChangedEvent, I>(self);
+        }
+        self
+    }
+
+    #[cfg(feature = "ui")]
+    fn log_ui_interactions(&mut self) -> &mut Self {
+        #[cfg(feature = "enabled")]
+        self.log_channel("UI", |app| {
+            app.log_trigger::<OnAdd, bevy::ui::widget::Button>()
+                .log_trigger::<OnRemove, bevy::ui::widget::Button>();
+            ui::plugin::<I>(app);
+        });
+        self
+    }
+
+    fn log_bevy_app_events(&mut self) -> &mut Self {
+        self.log_event::<AppExit>();
+        #[cfg(feature = "window")]
+        self.log_event::<bevy::window::AppLifecycle>();
+        self
+    }
+
+    #[cfg(feature = "window")]
+    fn log_bevy_window_events(&mut self) -> &mut Self {
+        use bevy::window::*;
+        self.log_event::<WindowCreated>()
+            .log_event::<WindowResized>()
+            .log_event::<WindowMoved>()
+            .log_event::<WindowCloseRequested>()
+            .log_event::<WindowClosing>()
+            .log_event::<WindowClosed>()
+            .log_event::<WindowDestroyed>()
+            .log_event::<WindowFocused>()
+            .log_event::<WindowOccluded>()
+            .log_event::<WindowScaleFactorChanged>()
+            .log_event::<WindowBackendScaleFactorChanged>()
+            .log_event::<WindowThemeChanged>()
+            .log_event::<CursorMoved>()
+            .log_event::<CursorEntered>()
+            .log_event::<CursorLeft>()
+            .log_event::<FileDragAndDrop>()
+            .log_event::<Ime>()
+    }
+
+    #[cfg(feature = "asset")]
+    fn log_bevy_asset_events<A: bevy::asset::Asset>(&mut self) -> &mut Self {
+        self.log_event::<bevy::asset::AssetEvent<A>>()
+            .log_event_with_formatter(|event: &bevy::asset::AssetLoadFailedEvent<A>| {
+                format!("{} failed to load: {}", event.path, event.error)
+            })
+    }
+
+    #[cfg(feature = "derive")]
+    fn log_all_derived(&mut self) -> &mut Self {
+        if std::any::TypeId::of::<I>() == std::any::TypeId::of::<()>() {
+            for derived in inventory::iter::<__private::DerivedEvent> {
+                (derived.0)(self);
+            }
+        } else {
+            warn!(
+                "The derived events can only be logged with the default instance, log_all_derived does nothing for \"{}\"",
+                std::any::type_name::<I>()
+            );
+        }
+        self
+    }
+
+    fn log_instance<J: LogInstance>(
+        &mut self,
+        f: impl FnOnce(&mut LogInstanceApp<J>),
+    ) -> &mut Self {
+        f(&mut LogInstanceApp::new(self.app));
+        self
+    }
+}
+
+impl LogEvent for App {
+    fn log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_event::<E>();
+        })
+    }
+
+    fn log_event_with_formatter<E>(
+        &mut self,
+        formatter: impl Fn(&E) -> String + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        E: Event,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_event_with_formatter(formatter);
+        })
+    }
+
+    fn log_event_reflect<E>(&mut self) -> &mut Self
+    where
+        E: Event + Reflect,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_event_reflect::<E>();
+        })
+    }
+
+    fn log_event_as<E>(&mut self, name: impl Into<String>) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_event_as::<E>(name);
+        })
+    }
+
+    fn add_and_log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.add_and_log_event::<E>();
+        })
+    }
+
+    fn add_and_log_state_scoped_event<E>(&mut self, state: impl FreelyMutableState) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.add_and_log_state_scoped_event::<E>(state);
+        })
+    }
+
+    fn log_trigger_many<E, B>(&mut self) -> &mut Self
+    where
+        E: Event,
+        B: LoggedComponents,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_trigger_many::<E, B>();
+        })
+    }
+
+    fn log_triggered<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_triggered::<E>();
+        })
+    }
+
+    fn log_trigger<E, C>(&mut self) -> &mut Self
+    where
+        E: Event,
+        C: Component + std::fmt::Debug,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_trigger::<E, C>();
+        })
+    }
+
+    fn log_trigger_cached<E, C>(&mut self) -> &mut Self
+    where
+        E: Event,
+        C: Component + std::fmt::Debug + Clone,
+    {
+        self.log_instance::<()>(|app| {
+            app.log_trigger_cached::<E, C>();
+        })
+    }
+
+    fn log_channel(&mut self, channel: impl Into<String>, f: impl FnOnce(&mut Self)) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_channel(channel, |app| f(app.app));
+        })
+    }
+
+    fn with_group(
+        &mut self,
+        group: impl Into<LogGroupToken>,
+        f: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.with_group(group, |app| f(app.app));
+        })
+    }
+
+    fn with_reader_start(&mut self, start: ReaderStart, f: impl FnOnce(&mut Self)) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.with_reader_start(start, |app| f(app.app));
+        })
+    }
+
+    fn log_gamepad_events(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_gamepad_events();
+        })
+    }
+
+    #[cfg(feature = "ui")]
+    fn log_ui_interactions(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_ui_interactions();
+        })
+    }
+
+    fn log_bevy_app_events(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_bevy_app_events();
+        })
+    }
+
+    #[cfg(feature = "window")]
+    fn log_bevy_window_events(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_bevy_window_events();
+        })
+    }
+
+    #[cfg(feature = "asset")]
+    fn log_bevy_asset_events<A: bevy::asset::Asset>(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_bevy_asset_events::<A>();
+        })
+    }
+
+    #[cfg(feature = "derive")]
+    fn log_all_derived(&mut self) -> &mut Self {
+        self.log_instance::<()>(|app| {
+            app.log_all_derived();
+        })
+    }
+
+    fn log_instance<I: LogInstance>(
+        &mut self,
+        f: impl FnOnce(&mut LogInstanceApp<I>),
+    ) -> &mut Self {
+        f(&mut LogInstanceApp::new(self));
+        self
+    }
+}
+
+/// A tuple of [Component] that can be logged in one call with
+/// [log_trigger_many](LogEvent::log_trigger_many). It is implemented for tuples of up to
+/// 12 components.
+pub trait LoggedComponents {
+    /// Calls [log_trigger](LogEvent::log_trigger) for each [Component] of the tuple.
+    fn log_trigger_each<E: Event>(app: &mut impl LogEvent);
+}
+
+macro_rules! impl_logged_components {
+    ($($C:ident),*) => {
+        impl<$($C: Component + std::fmt::Debug),*> LoggedComponents for ($($C,)*) {
+            fn log_trigger_each<E: Event>(app: &mut impl LogEvent) {
+                $(app.log_trigger::<E, $C>();)*
+            }
+        }
+    };
+}
+
+impl_logged_components!(C0);
+impl_logged_components!(C0, C1);
+impl_logged_components!(C0, C1, C2);
+impl_logged_components!(C0, C1, C2, C3);
+impl_logged_components!(C0, C1, C2, C3, C4);
+impl_logged_components!(C0, C1, C2, C3, C4, C5);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6, C7);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6, C7, C8);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6, C7, C8, C9);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10);
+impl_logged_components!(C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11);
+
+/// The Trait implemented on [World] to log [Event] registered while the [App] is
+/// already running, for example from an exclusive system or a command.
+///
+/// Its functions work like the ones of the [LogEvent] trait, except that the events
+/// are registered right away instead of in the [RegisterEventsSet], so their saved
+/// settings are restored and they appear in the settings window immediately.
+///
+/// As an example :
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_log_events::prelude::*;
+/// # #[derive(Event, Debug)]
+/// # struct LevelLoaded;
+/// # fn log_level_events(mut commands: Commands) {
+/// commands.queue(|world: &mut World| {
+///     world.log_event::<LevelLoaded>();
+/// });
+/// # }
+/// ```
+///
+/// The [LogEventsPlugin] must have been added to the [App]. As these functions add
+/// systems to the [PreUpdate] schedule and to the schedule of the [LogEventsSet], they
+/// must not be used from inside these schedules.
+pub trait LogEventWorld {
+    /// See [LogEvent::log_event].
+    fn log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug;
+
+    /// See [LogEvent::log_event_with_formatter].
+    fn log_event_with_formatter<E>(
+        &mut self,
+        formatter: impl Fn(&E) -> String + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        E: Event;
+
+    /// See [LogEvent::log_event_reflect].
+    fn log_event_reflect<E>(&mut self) -> &mut Self
+    where
+        E: Event + Reflect;
+
+    /// See [LogEvent::log_triggered].
+    fn log_triggered<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug;
+
+    /// See [LogEvent::log_trigger].
+    fn log_trigger<E, C>(&mut self) -> &mut Self
+    where
+        E: Event,
+        C: Component + std::fmt::Debug;
+}
+
+impl LogEventWorld for World {
+    fn log_event<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        #[cfg(feature = "enabled")]
+        if can_register_at_runtime::<LoggedEventSettings<E>>(self, type_name::<E>()) {
+            self.insert_resource(new_queued_settings::<E, ()>(self));
+            let schedule = log_schedule::<()>(self);
+            self.resource_mut::<Schedules>()
+                .add_systems(schedule, log_event::<E, ()>.in_set(InstanceSet::of::<()>()));
+            register_sent_event::<E, ()>(self);
+        }
+        self
+    }
+
+    fn log_event_with_formatter<E>(
+        &mut self,
+        formatter: impl Fn(&E) -> String + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        E: Event,
+    {
+        #[cfg(feature = "enabled")]
+        if can_register_at_runtime::<LoggedEventSettings<E>>(self, type_name::<E>()) {
+            self.insert_resource(new_queued_settings::<E, ()>(self));
+            self.insert_resource(EventFormatter::<E>(Box::new(formatter)));
+            let schedule = log_schedule::<()>(self);
+            self.resource_mut::<Schedules>().add_systems(
+                schedule,
+                log_formatted_event::<E, ()>.in_set(InstanceSet::of::<()>()),
+            );
+            register_sent_event::<E, ()>(self);
+        }
+        #[cfg(not(feature = "enabled"))]
+        let _ = formatter;
+        self
+    }
+
+    fn log_event_reflect<E>(&mut self) -> &mut Self
+    where
+        E: Event + Reflect,
+    {
+        #[cfg(feature = "enabled")]
+        if can_register_at_runtime::<LoggedEventSettings<E>>(self, type_name::<E>()) {
+            self.insert_resource(new_queued_settings::<E, ()>(self));
+            let schedule = log_schedule::<()>(self);
+            self.resource_mut::<Schedules>().add_systems(
+                schedule,
+                log_reflected_event::<E, ()>.in_set(InstanceSet::of::<()>()),
+            );
+            register_sent_event::<E, ()>(self);
+        }
+        self
+    }
+
+    fn log_triggered<E>(&mut self) -> &mut Self
+    where
+        E: Event + std::fmt::Debug,
+    {
+        #[cfg(feature = "enabled")]
+        if can_register_at_runtime::<LoggedEventSettings<E>>(self, type_name::<E>()) {
+            add_runtime_log_observer::<(), _, _, _>(
+                self,
+                log_triggered::<E, ()>,
+                format!("LogTrigger<{}>", type_name::<E>()),
+            );
+            self.insert_resource(new_logged_settings::<E, ()>(self));
+            register_event::<E, ()>(self);
+        }
+        self
+    }
+
+    fn log_trigger<E, C>(&mut self) -> &mut Self
+    where
+        E: Event,
+        C: Component + std::fmt::Debug,
+    {
+        #[cfg(feature = "enabled")]
+        if can_register_at_runtime::<LoggedEventSettings<E, C>>(self, &trigger_name::<E, C>()) {
+            add_runtime_log_observer::<(), _, _, _>(
+                self,
+                log_component::<E, C, ()>,
+                format!("Log{}", trigger_name::<E, C>()),
+            );
+            self.insert_resource(new_logged_settings::<E, C>(self));
+            let schedule = log_schedule::<()>(self);
+            self.resource_mut::<Schedules>().add_systems(
+                schedule,
+                flush_burst::<E, C, ()>.in_set(InstanceSet::of::<()>()),
+            );
+            register_component::<E, C, ()>(self);
+        }
+        self
+    }
+}
+
+/// Whether the settings `S` of the event `name` can be registered in `world`.
+#[cfg(feature = "enabled")]
+fn can_register_at_runtime<S: Resource>(world: &World, name: &str) -> bool {
+    if !world.contains_resource::<LogEventsPluginSettings>() {
+        warn!(
+            "You tried to log \"{}\" but the LogEventsPlugin was not added",
+            name
+        );
+        false
+    } else if world.contains_resource::<S>() {
+        warn!("You tried to log \"{}\" twice", name);
+        false
+    } else {
+        true
+    }
+}
+
+#[cfg(feature = "enabled")]
+fn log_gamepad_event_type<E, I>(app: &mut App)
+where
+    E: GamepadEvent,
+    I: LogInstance,
+{
+    if !app.world().contains_resource::<LoggedEventSettings<E>>() {
+        app.insert_resource(new_queued_settings::<E, I>(app.world()));
+        add_to_log_schedule::<I, _>(
+            app,
+            log_gamepad_event::<E, I>.in_set(InstanceSet::of::<I>()),
+        );
+        queue_registration::<I>(app, type_name::<E>(), register_sent_event::<E, I>);
+    } else {
+        warn!(
+            "You tried to use log_event twice for the event \"{}\"",
+            type_name::<E>()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "enabled"))]
+mod tests {
+    use super::*;
+
+    #[derive(Event, Debug)]
+    struct LevelLoaded;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)))
+            .add_event::<LevelLoaded>();
+        app.finish();
+        app.update();
+        app
+    }
+
+    fn level_loaded_row(app: &App) -> Option<ui_model::EventRow> {
+        ui_model::rows(app.world(), &default())
+            .into_iter()
+            .find(|row| row.name.ends_with("LevelLoaded"))
+    }
+
+    #[test]
+    fn register_while_running() {
+        let mut app = test_app();
+        assert!(level_loaded_row(&app).is_none());
+        app.world_mut().log_event::<LevelLoaded>();
+        let row = level_loaded_row(&app).expect("the event is registered right away");
+        assert!(row.settings.enabled);
+        app.world_mut().send_event(LevelLoaded);
+        app.update();
+        assert_eq!(level_loaded_row(&app).unwrap().log_count, 1);
+    }
+
+    #[test]
+    fn register_twice_at_runtime() {
+        let mut app = test_app();
+        app.world_mut().log_event::<LevelLoaded>();
+        app.world_mut().log_event::<LevelLoaded>();
+        app.world_mut().send_event(LevelLoaded);
+        app.update();
+        assert_eq!(level_loaded_row(&app).unwrap().log_count, 1);
+    }
+
+    mod other {
+        #[derive(bevy::prelude::Event, Debug)]
+        pub struct LevelLoaded;
+    }
+
+    #[derive(Event, Debug)]
+    struct Unique;
+
+    fn registered_names(other_first: bool) -> Vec<String> {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((
+            MinimalPlugins,
+            LogEventsPlugin::new(path).with_short_names(),
+        ));
+        if other_first {
+            app.add_and_log_event::<other::LevelLoaded>()
+                .add_and_log_event::<LevelLoaded>();
+        } else {
+            app.add_and_log_event::<LevelLoaded>()
+                .add_and_log_event::<other::LevelLoaded>();
+        }
+        app.add_and_log_event::<Unique>();
+        app.finish();
+        app.update();
+        let mut names: Vec<_> = ui_model::rows(app.world(), &default())
+            .into_iter()
+            .map(|row| row.name)
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn shared_short_names_keep_full_names() {
+        let names = registered_names(false);
+        assert_eq!(names, registered_names(true));
+        assert!(names.contains(&"Unique".to_string()));
+        assert!(names.contains(&type_name::<LevelLoaded>().to_string()));
+        assert!(names.contains(&type_name::<other::LevelLoaded>().to_string()));
+    }
+
+    fn has_log_event_system(app: &App, schedule: impl ScheduleLabel) -> bool {
+        app.get_schedule(schedule).is_some_and(|schedule| {
+            schedule
+                .graph()
+                .systems()
+                .any(|(_, system, _)| system.name().contains("log_event<"))
+        })
+    }
+
+    #[test]
+    fn registered_before_plugin_in_schedule() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins(MinimalPlugins)
+            .add_and_log_event::<LevelLoaded>()
+            .add_plugins(LogEventsPlugin::new(path).in_schedule(PostUpdate));
+        assert!(has_log_event_system(&app, PostUpdate));
+        assert!(!has_log_event_system(&app, Last));
+    }
+
+    #[derive(Event, Debug)]
+    struct Pinged;
+
+    #[test]
+    fn history_in_emission_order() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((
+            MinimalPlugins,
+            LogEventsPlugin::new(path).with_log_history(8),
+        ))
+        .add_and_log_event::<LevelLoaded>()
+        .log_triggered::<Pinged>()
+        .add_systems(PreUpdate, |mut events: EventWriter<LevelLoaded>| {
+            events.send(LevelLoaded);
+        })
+        .add_systems(Update, |mut commands: Commands| commands.trigger(Pinged));
+        app.finish();
+        app.update();
+        app.update();
+        let names: Vec<_> = app
+            .world()
+            .resource::<history::LogHistory>()
+            .iter()
+            .map(|entry| entry.name.rsplit(':').next().unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["LevelLoaded", "Pinged", "LevelLoaded", "Pinged"]);
+    }
+
+    #[derive(Component, Debug)]
+    struct Health;
+
+    #[test]
+    fn history_by_entity() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((
+            MinimalPlugins,
+            LogEventsPlugin::new(path).with_log_history(8),
+        ))
+        .log_trigger::<OnAdd, Health>()
+        .log_triggered::<Pinged>();
+        app.finish();
+        app.update();
+        let player = app.world_mut().spawn(Health).id();
+        app.world_mut().spawn(Health);
+        app.world_mut().trigger_targets(Pinged, player);
+        app.world_mut().trigger(Pinged);
+        app.update();
+        let history = app.world().resource::<history::LogHistory>();
+        assert_eq!(history.len(), 4);
+        let texts: Vec<_> = history
+            .filter_by_entity(player)
+            .map(|entry| entry.text.as_str())
+            .collect();
+        assert_eq!(texts.len(), 2);
+        assert!(texts[0].starts_with("OnAdd"));
+        assert!(texts[1].ends_with("Pinged"));
+    }
+
+    #[derive(Event, Debug, Reflect)]
+    struct Damage {
+        amount: u32,
+    }
+
+    impl std::fmt::Display for Damage {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{} damage", self.amount)
+        }
+    }
+
+    #[test]
+    fn payload_formats() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((
+            MinimalPlugins,
+            LogEventsPlugin::new(path)
+                .with_log_history(8)
+                .with_display::<Damage>()
+                .with_formatter("hp", |damage: &Damage| format!("-{} HP", damage.amount)),
+        ))
+        .register_type::<Damage>()
+        .log_triggered::<Damage>();
+        app.finish();
+        app.update();
+        for format in [
+            LogFormat::Display,
+            LogFormat::Json,
+            LogFormat::Custom("hp".into()),
+            LogFormat::Custom("missing".into()),
+        ] {
+            app.world_mut()
+                .resource_mut::<LoggedEventSettings<Damage>>()
+                .format = format;
+            app.world_mut().trigger(Damage { amount: 3 });
+        }
+        app.update();
+        let history = app.world().resource::<history::LogHistory>();
+        let texts: Vec<_> = history.iter().map(|entry| entry.text.as_str()).collect();
+        assert_eq!(texts.len(), 4);
+        assert!(texts[0].ends_with("3 damage"));
+        assert!(texts[1].ends_with(r#"{"amount":3}"#));
+        assert!(texts[2].ends_with("-3 HP"));
+        // A format without formatter falls back to the compact one.
+        assert!(texts[3].ends_with("Damage { amount: 3 }"));
+    }
+
+    #[test]
+    fn early_lines_are_capped() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)))
+            .log_triggered::<Pinged>();
+        for _ in 0..systems::MAX_EARLY_LINES + 10 {
+            app.world_mut().trigger(Pinged);
+        }
+        app.finish();
+        app.update();
+        let settings = app.world().resource::<LoggedEventSettings<Pinged>>();
+        let activity = settings.stats.lock();
+        assert!(activity.early.is_empty());
+        assert_eq!(activity.early_dropped, 0);
+        assert_eq!(activity.logged, systems::MAX_EARLY_LINES as u64);
+    }
+
+    #[test]
+    fn handle_drained_while_disabled() {
+        let mut app = test_app();
+        app.world_mut().log_event::<LevelLoaded>();
+        let handle = app
+            .world()
+            .resource::<LoggedEventSettings<LevelLoaded>>()
+            .handle()
+            .unwrap();
+        app.world_mut()
+            .resource_mut::<LogEventsPluginSettings>()
+            .enabled = false;
+        for _ in 0..=LOG_HANDLE_CAPACITY {
+            handle.log(LevelLoaded);
+        }
+        app.update();
+        let settings = app.world().resource::<LoggedEventSettings<LevelLoaded>>();
+        let queue = settings.queue.as_ref().unwrap();
+        assert!(queue.receiver.is_empty());
+        assert_eq!(queue.dropped.load(Ordering::Relaxed), 0);
+        assert_eq!(level_loaded_row(&app).unwrap().log_count, 0);
+    }
+
+    struct Other;
+
+    fn logged_by<I: LogInstance>(app: &App, name: &str) -> Option<u64> {
+        let ids = app.world().resource::<systems::LogSettingsIds<I>>();
+        ids.get(name)
+            .map(|registered| registered.stats.lock().logged)
+    }
+
+    #[test]
+    fn instances_are_independent() {
+        let mut app = App::new();
+        let dir = std::env::temp_dir().join("bevy_log_events_tests");
+        app.add_plugins((
+            MinimalPlugins,
+            LogEventsPlugin::new(dir.join("missing.ron")),
+            LogEventsPlugin::<Other>::new_instance(dir.join("missing_other.ron")),
+        ))
+        .add_and_log_event::<LevelLoaded>()
+        .log_instance::<Other>(|app| {
+            app.add_and_log_event::<Pinged>();
+        });
+        app.finish();
+        app.update();
+        let level_loaded = type_name::<LevelLoaded>();
+        let pinged = type_name::<Pinged>();
+        assert_eq!(logged_by::<()>(&app, level_loaded), Some(0));
+        assert_eq!(logged_by::<()>(&app, pinged), None);
+        assert_eq!(logged_by::<Other>(&app, pinged), Some(0));
+        assert_eq!(logged_by::<Other>(&app, level_loaded), None);
+
+        app.world_mut()
+            .resource_mut::<LogEventsPluginSettings>()
+            .enabled = false;
+        app.world_mut().send_event(LevelLoaded);
+        app.world_mut().send_event(Pinged);
+        app.update();
+        assert_eq!(logged_by::<()>(&app, level_loaded), Some(0));
+        assert_eq!(logged_by::<Other>(&app, pinged), Some(1));
+    }
+
+    #[test]
+    fn register_without_plugin() {
+        let mut world = World::new();
+        world.log_event::<LevelLoaded>();
+        assert!(!world.contains_resource::<LoggedEventSettings<LevelLoaded>>());
+    }
 }
@@ -0,0 +1,115 @@
+//! Writes the logs of the events whose [output](crate::EventSettings::output) is a file.
+
+use std::{
+    fs::{create_dir_all, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use bevy::{log::Level, prelude::*, time::common_conditions::on_real_timer, utils::HashMap};
+
+use crate::{
+    systems::log_schedule, LogEventsPluginSettings, LogEventsSet, LogInstance, LOG_TARGET,
+};
+
+/// The lines logged since the end of the last [LogEventsSet], written to their files
+/// there. They are kept in the [LogEventsPluginSettings] as the logs are made from places
+/// without access to the [World].
+#[derive(Default)]
+pub(crate) struct PendingLines(Mutex<Vec<(PathBuf, String)>>);
+
+impl PendingLines {
+    pub(crate) fn push(&self, path: PathBuf, level: Level, to_log: &str) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((path, format!("{} {}\n", level.as_str(), to_log)));
+    }
+}
+
+/// The file where the logs of the event `name` are written, see
+/// [output_files](LogEventsPluginSettings::output_files).
+pub(crate) fn output_path<I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    name: &str,
+) -> PathBuf {
+    plugin_settings
+        .output_files
+        .get(name)
+        .cloned()
+        .unwrap_or_else(|| default_output_path(name))
+}
+
+/// `logs/` followed by `name` with the characters that can not be in a file name
+/// replaced, like `logs/my_game__DamageEvent.log`.
+pub(crate) fn default_output_path(name: &str) -> PathBuf {
+    let file_name: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    Path::new("logs").join(file_name + ".log")
+}
+
+/// The files the logs are written in, or [None] when one could not be opened. They are
+/// shared by the instances of the [LogEventsPlugin](crate::LogEventsPlugin).
+#[derive(Resource, Default)]
+struct LogFiles(HashMap<PathBuf, Option<BufWriter<File>>>);
+
+/// How often the files are flushed, so they can be followed while the program runs.
+const FLUSH_PERIOD: Duration = Duration::from_secs(1);
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    let schedule = log_schedule::<I>(app.world());
+    app.add_systems(schedule, write_lines::<I>.after(LogEventsSet));
+    if !app.world().contains_resource::<LogFiles>() {
+        app.init_resource::<LogFiles>()
+            .add_systems(Last, flush_files.run_if(on_real_timer(FLUSH_PERIOD)))
+            .add_systems(PostUpdate, flush_files.run_if(on_event::<AppExit>));
+    }
+}
+
+fn open(path: &Path) -> io::Result<BufWriter<File>> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    Ok(BufWriter::new(file))
+}
+
+fn write_lines<I: LogInstance>(
+    mut files: ResMut<LogFiles>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+) {
+    let lines = std::mem::take(
+        &mut *plugin_settings
+            .pending_lines
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    for (path, line) in lines {
+        let file = files.0.entry(path).or_insert_with_key(|path| {
+            open(path)
+                .inspect_err(|err| {
+                    warn!(target: LOG_TARGET, "Could not open the log file {:?}: {}", path, err);
+                })
+                .ok()
+        });
+        if let Some(writer) = file {
+            if let Err(err) = writer.write_all(line.as_bytes()) {
+                warn!(target: LOG_TARGET, "Could not write in a log file: {}", err);
+                *file = None;
+            }
+        }
+    }
+}
+
+fn flush_files(mut files: ResMut<LogFiles>) {
+    for writer in files.0.values_mut().flatten() {
+        if let Err(err) = writer.flush() {
+            warn!(target: LOG_TARGET, "Could not write in a log file: {}", err);
+        }
+    }
+}
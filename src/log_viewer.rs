@@ -0,0 +1,183 @@
+//! A window showing the logs kept by the [LogHistory], so they can be read without
+//! leaving the game.
+
+use std::marker::PhantomData;
+
+use bevy::{log::Level, prelude::*, utils::HashSet};
+use bevy_egui::egui;
+
+use crate::{
+    history::{LogEntry, LogHistory},
+    settings_window::{
+        colored_text_level, find_egui_context, instance_title, level_color, ALL_LEVELS,
+    },
+    systems::LogSettingsIds,
+    LogEventsPluginSettings, LogInstance,
+};
+
+pub(crate) const LOG_VIEWER_NAME: &str = "Logged Events";
+
+/// The state of the log viewer window of the instance `I` of the plugin.
+#[derive(Resource)]
+pub(crate) struct LogViewerState<I> {
+    /// The text searched in the logs, ignoring the case.
+    filter: String,
+    /// The least severe [Level] shown.
+    level: Level,
+    /// The only [Entity] whose logs are shown, chosen by clicking on one of its logs.
+    entity: Option<Entity>,
+    /// The logs shown while the viewer is paused.
+    paused: Option<Vec<LogEntry>>,
+    /// Whether the viewer scrolls to the new logs.
+    auto_scroll: bool,
+    _instance: PhantomData<fn() -> I>,
+}
+
+impl<I> Default for LogViewerState<I> {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            level: Level::TRACE,
+            entity: None,
+            paused: None,
+            auto_scroll: true,
+            _instance: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    app.init_resource::<LogViewerState<I>>()
+        .add_systems(Update, show_log_viewer::<I>.run_if(log_viewer_shown::<I>));
+}
+
+fn log_viewer_shown<I: LogInstance>(plugin_settings: Res<LogEventsPluginSettings<I>>) -> bool {
+    plugin_settings.show_log_viewer
+}
+
+fn show_log_viewer<I: LogInstance>(world: &mut World) {
+    let mut open = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .show_log_viewer;
+    if let Some(mut egui_context) = find_egui_context::<I>(world) {
+        world.resource_scope(|world, mut state: Mut<LogViewerState<I>>| {
+            egui::Window::new(instance_title::<I>(LOG_VIEWER_NAME))
+                .open(&mut open)
+                .default_size([600., 300.])
+                .show(egui_context.get_mut(), |ui| {
+                    log_viewer_window_ui(world, ui, &mut state);
+                });
+        });
+        world
+            .resource_mut::<LogEventsPluginSettings<I>>()
+            .show_log_viewer = open;
+    }
+}
+
+pub(crate) fn log_viewer_window_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogViewerState<I>,
+) {
+    let palette = world.resource::<LogEventsPluginSettings<I>>().level_palette;
+    // The history is shared by the instances of the plugin, only the logs of the events
+    // of this one are shown.
+    let names: HashSet<String> = world
+        .resource::<LogSettingsIds<I>>()
+        .keys()
+        .cloned()
+        .collect();
+    let Some(mut history) = world.get_resource_mut::<LogHistory>() else {
+        ui.label("Build the LogEventsPlugin with_log_history to see the logs here");
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.filter).hint_text("Search"));
+        egui::ComboBox::from_id_salt("log_viewer_level")
+            .selected_text(colored_text_level(state.level, palette))
+            .show_ui(ui, |ui| {
+                for level in ALL_LEVELS {
+                    ui.selectable_value(
+                        &mut state.level,
+                        level,
+                        colored_text_level(level, palette),
+                    );
+                }
+            })
+            .response
+            .on_hover_text("The least severe level shown");
+        let pause = if state.paused.is_some() {
+            "Resume"
+        } else {
+            "Pause"
+        };
+        if ui.button(pause).clicked() {
+            state.paused = match state.paused {
+                Some(_) => None,
+                None => Some(history.iter().cloned().collect()),
+            };
+        }
+        ui.checkbox(&mut state.auto_scroll, "Auto-scroll");
+        if ui.button("Clear").clicked() {
+            history.clear();
+            if let Some(paused) = &mut state.paused {
+                paused.clear();
+            }
+        }
+    });
+    if let Some(entity) = state.entity {
+        ui.horizontal(|ui| {
+            ui.label(format!("Only the logs of {}", entity));
+            if ui.button("Show all").clicked() {
+                state.entity = None;
+            }
+        });
+    }
+    ui.separator();
+    let filter = state.filter.to_lowercase();
+    let all: Vec<&LogEntry> = match &state.paused {
+        Some(paused) => paused.iter().collect(),
+        None => history.iter().collect(),
+    };
+    let entries: Vec<_> = all
+        .into_iter()
+        .filter(|entry| names.contains(&entry.name))
+        .filter(|entry| entry.level <= state.level)
+        .filter(|entry| state.entity.is_none() || entry.entity == state.entity)
+        .filter(|entry| filter.is_empty() || entry.text.to_lowercase().contains(&filter))
+        .collect();
+    let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+    let mut clicked = None;
+    egui::ScrollArea::both()
+        .auto_shrink(false)
+        .stick_to_bottom(state.auto_scroll)
+        .show_rows(ui, row_height, entries.len(), |ui, rows| {
+            for entry in &entries[rows] {
+                let text = egui::RichText::new(format!(
+                    "{:>6} {:<5} {}",
+                    entry.frame,
+                    entry.level.as_str(),
+                    entry.text
+                ))
+                .monospace()
+                .color(level_color(entry.level, palette));
+                let Some(entity) = entry.entity else {
+                    ui.label(text).on_hover_text(entry.name.as_str());
+                    continue;
+                };
+                if ui
+                    .add(egui::Label::new(text).sense(egui::Sense::click()))
+                    .on_hover_text(format!(
+                        "{}\nClick to see only the logs of {}",
+                        entry.name, entity
+                    ))
+                    .clicked()
+                {
+                    clicked = Some(entity);
+                }
+            }
+        });
+    if clicked.is_some() {
+        state.entity = clicked;
+    }
+}
@@ -0,0 +1,103 @@
+//! A minimal HTTP endpoint exposing the activity of the events as Prometheus metrics.
+
+use std::{
+    fmt::Write as _,
+    io::{self, Read, Write},
+    marker::PhantomData,
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use bevy::{prelude::*, time::common_conditions::on_real_timer};
+
+use crate::{systems::LogSettingsIds, utils::get_log_settings_by_id, LogInstance, LOG_TARGET};
+
+/// The metrics of the instance `I` in the Prometheus text format, rendered from the
+/// [World] and served by another thread.
+#[derive(Resource, Clone)]
+struct MetricsText<I>(Arc<Mutex<String>>, PhantomData<fn() -> I>);
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App, address: SocketAddr) {
+    let listener = match TcpListener::bind(address) {
+        Ok(listener) => listener,
+        Err(err) => {
+            warn!(target: LOG_TARGET, "Could not serve the metrics on {}: {}", address, err);
+            return;
+        }
+    };
+    let text = MetricsText::<I>(default(), PhantomData);
+    let served = text.0.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            if let Err(err) = respond(stream, &served) {
+                debug!(target: LOG_TARGET, "Could not send the metrics: {}", err);
+            }
+        }
+    });
+    app.insert_resource(text).add_systems(
+        Last,
+        update_metrics::<I>.run_if(on_real_timer(Duration::from_secs(1))),
+    );
+}
+
+/// How long a client can take to send its request or to read the response.
+const RESPONSE_TIMEOUT: Duration = Duration::from_millis(100);
+
+fn respond(mut stream: TcpStream, text: &Mutex<String>) -> io::Result<()> {
+    // The request does not matter, every path gets the metrics. The timeouts are short as
+    // the connections are answered one at a time.
+    stream.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+    stream.set_write_timeout(Some(RESPONSE_TIMEOUT))?;
+    let mut request = [0; 1024];
+    let _ = stream.read(&mut request)?;
+    let body = text.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn update_metrics<I: LogInstance>(world: &World) {
+    let mut logged = String::from(
+        "# HELP bevy_log_events_logged_total Number of logs of each event since the start.\n\
+         # TYPE bevy_log_events_logged_total counter\n",
+    );
+    let mut enabled = String::from(
+        "# HELP bevy_log_events_enabled Whether each event is enabled.\n\
+         # TYPE bevy_log_events_enabled gauge\n",
+    );
+    for (name, registered) in world.resource::<LogSettingsIds<I>>().iter() {
+        let name = escape_label(name);
+        let _ = writeln!(
+            logged,
+            "bevy_log_events_logged_total{{event=\"{}\"}} {}",
+            name,
+            registered.stats.lock().logged
+        );
+        let _ = writeln!(
+            enabled,
+            "bevy_log_events_enabled{{event=\"{}\"}} {}",
+            name,
+            u8::from(
+                get_log_settings_by_id(world, &registered.id)
+                    .is_some_and(|settings| settings.enabled)
+            )
+        );
+    }
+    logged.push_str(&enabled);
+    *world
+        .resource::<MetricsText<I>>()
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = logged;
+}
@@ -0,0 +1,66 @@
+//! Shows the logs of the events with [notify](crate::EventSettings::notify) as desktop
+//! notifications, one at a time from a single thread.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use crossbeam_channel::{bounded, Receiver, Sender, TrySendError};
+
+use crate::LOG_TARGET;
+
+/// The number of notifications that can wait to be shown before new ones are dropped.
+const QUEUE_CAPACITY: usize = 16;
+
+/// The time left between two notifications, so a burst of logs does not flood the
+/// desktop.
+const COOLDOWN: Duration = Duration::from_millis(500);
+
+/// Sends the notifications to the thread showing them, started with the first one. The
+/// thread stops once this is dropped with the [LogEventsPluginSettings](crate::LogEventsPluginSettings).
+#[derive(Default)]
+pub(crate) struct Notifier {
+    sender: OnceLock<Sender<(String, String)>>,
+    /// Whether the dropped notifications were already reported.
+    drop_warned: AtomicBool,
+}
+
+impl Notifier {
+    /// Show `to_log` in a notification titled `name`, unless too many notifications are
+    /// waiting.
+    pub(crate) fn notify(&self, name: &str, to_log: &str) {
+        let sender = self.sender.get_or_init(|| {
+            let (sender, receiver) = bounded(QUEUE_CAPACITY);
+            std::thread::spawn(move || show_notifications(receiver));
+            sender
+        });
+        match sender.try_send((name.to_string(), to_log.to_string())) {
+            Err(TrySendError::Full(_)) if !self.drop_warned.swap(true, Ordering::Relaxed) => {
+                warn!(
+                    target: LOG_TARGET,
+                    "More than {} notifications are waiting, the new ones are dropped",
+                    QUEUE_CAPACITY
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn show_notifications(receiver: Receiver<(String, String)>) {
+    for (name, to_log) in receiver {
+        if let Err(err) = notify_rust::Notification::new()
+            .summary(&name)
+            .body(&to_log)
+            .show()
+        {
+            warn!(target: LOG_TARGET, "Could not show a notification: {}", err);
+        }
+        std::thread::sleep(COOLDOWN);
+    }
+}
@@ -0,0 +1,192 @@
+//! Find the schedule of the main loop in which the logged events were sent or triggered,
+//! for their [send_schedule](crate::EventSettings::send_schedule) setting.
+//!
+//! A schedule is run after each schedule of the [MainScheduleOrder] to note how many
+//! events had been sent when it ended. The events sent in a schedule run by another one,
+//! like `FixedUpdate`, are attributed to the outer schedule.
+//!
+//! Another schedule is run before each of them to note the [CurrentSchedule], in which
+//! the triggers are said to be triggered as they are logged right away.
+//!
+//! The end of the schedules is also noted for the [LogHistory](crate::history::LogHistory),
+//! which orders the logs by when their event was sent.
+
+use std::marker::PhantomData;
+
+use bevy::{
+    app::MainScheduleOrder,
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+    utils::Instant,
+};
+
+use crate::{systems::log_schedule, LogEventsPluginSettings, LogInstance, LoggedEventSettings};
+
+/// The schedule run right after the schedule it contains.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct AfterSchedule(InternedScheduleLabel);
+
+/// The schedule run right before the schedule it contains.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct BeforeSchedule(InternedScheduleLabel);
+
+/// The schedules of the main loop followed by an [AfterSchedule].
+#[derive(Resource)]
+struct MarkedSchedules(Vec<InternedScheduleLabel>);
+
+/// The schedule of the main loop, or of its startup, that is running.
+#[derive(Resource, Default)]
+pub(crate) struct CurrentSchedule(Option<InternedScheduleLabel>);
+
+/// For each schedule that ended since the events `E` were last logged, the number of
+/// events `E` sent when it ended and when it ended.
+#[derive(Resource)]
+pub(crate) struct SentSchedules<E> {
+    ends: Vec<(usize, InternedScheduleLabel, Instant)>,
+    /// The schedule where the events are logged, in which the events sent after the
+    /// last marked schedule are.
+    current: InternedScheduleLabel,
+    _event: PhantomData<E>,
+}
+
+impl<E> SentSchedules<E> {
+    /// The schedule in which the event with `id` was sent and when it ended, or now for
+    /// the schedule where the events are logged. The events must be asked in the order
+    /// they were sent as the schedules that ended before are forgotten.
+    fn schedule_of(&mut self, id: usize) -> (InternedScheduleLabel, Instant) {
+        self.ends.retain(|(end, _, _)| id < *end);
+        self.ends
+            .first()
+            .map_or((self.current, Instant::now()), |(_, schedule, ended)| {
+                (*schedule, *ended)
+            })
+    }
+}
+
+/// Run an [AfterSchedule] after each schedule of the main loop and a [BeforeSchedule]
+/// before each schedule of the main loop and of its startup. Must be called once the
+/// [MainScheduleOrder] is complete. Does nothing when it was already called for another
+/// instance of the [LogEventsPlugin](crate::LogEventsPlugin).
+pub(crate) fn plugin(app: &mut App) {
+    if app.world().contains_resource::<MarkedSchedules>() {
+        return;
+    }
+    let Some(order) = app.world().get_resource::<MainScheduleOrder>() else {
+        return;
+    };
+    let labels = order.labels.clone();
+    let startup_labels = order.startup_labels.clone();
+    let mut order = app.world_mut().resource_mut::<MainScheduleOrder>();
+    for label in &labels {
+        order.insert_before(*label, BeforeSchedule(*label));
+        order.insert_after(*label, AfterSchedule(*label));
+    }
+    for label in &startup_labels {
+        order.insert_startup_before(*label, BeforeSchedule(*label));
+    }
+    for label in labels.iter().chain(&startup_labels) {
+        let label = *label;
+        app.add_systems(
+            BeforeSchedule(label),
+            move |mut current: ResMut<CurrentSchedule>| current.0 = Some(label),
+        );
+    }
+    for label in &labels {
+        app.init_schedule(AfterSchedule(*label));
+    }
+    app.init_resource::<CurrentSchedule>()
+        .insert_resource(MarkedSchedules(labels));
+}
+
+/// Note in which schedule the events `E` are sent, when they must be logged with it or
+/// kept by the [LogHistory](crate::history::LogHistory).
+pub(crate) fn track_send_schedule<E: Event, I: LogInstance>(world: &mut World) {
+    let Some(labels) = world
+        .get_resource::<MarkedSchedules>()
+        .map(|marked| marked.0.clone())
+    else {
+        return;
+    };
+    if world.contains_resource::<SentSchedules<E>>() {
+        return;
+    }
+    world.insert_resource(SentSchedules::<E> {
+        ends: Vec::new(),
+        current: log_schedule::<I>(world),
+        _event: PhantomData,
+    });
+    let mut schedules = world.resource_mut::<Schedules>();
+    for label in labels {
+        let mark = move |mut events: EventReader<E>, mut sent: ResMut<SentSchedules<E>>| {
+            if let Some(end) = events.read_with_id().map(|(_, id)| id.id + 1).last() {
+                sent.ends.push((end, label, Instant::now()));
+            }
+        };
+        schedules.add_systems(AfterSchedule(label), mark.run_if(must_track::<E, I>));
+    }
+}
+
+fn must_track<E: Event, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+) -> bool {
+    (settings.send_schedule || plugin_settings.history.is_some())
+        && plugin_settings.must_log(&settings)
+}
+
+/// The schedule in which a trigger logged now was triggered, if it is known.
+pub(crate) fn trigger_schedule(
+    current: &Option<Res<CurrentSchedule>>,
+) -> Option<InternedScheduleLabel> {
+    current.as_ref()?.0
+}
+
+/// The schedule in which the event with `id` was sent and when it ended, if it is known.
+pub(crate) fn send_schedule<E>(
+    sent: &mut Option<ResMut<SentSchedules<E>>>,
+    id: Option<usize>,
+) -> Option<(InternedScheduleLabel, Instant)>
+where
+    E: Send + Sync + 'static,
+{
+    Some(sent.as_mut()?.schedule_of(id?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogEventsPlugin;
+
+    #[derive(Event)]
+    struct Pinged;
+
+    #[derive(Resource, Default)]
+    struct TriggeredIn(Vec<InternedScheduleLabel>);
+
+    #[test]
+    fn triggers_know_their_schedule() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)))
+            .init_resource::<TriggeredIn>()
+            .add_observer(
+                |_: Trigger<Pinged>,
+                 current: Option<Res<CurrentSchedule>>,
+                 mut triggered: ResMut<TriggeredIn>| {
+                    triggered.0.extend(trigger_schedule(&current));
+                },
+            )
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.trigger(Pinged);
+            })
+            .add_systems(PostUpdate, |mut commands: Commands| {
+                commands.trigger(Pinged);
+            });
+        app.finish();
+        app.update();
+        assert_eq!(
+            app.world().resource::<TriggeredIn>().0,
+            [Startup.intern(), PostUpdate.intern()]
+        );
+    }
+}
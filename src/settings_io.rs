@@ -0,0 +1,865 @@
+//! Read and write the settings files of the plugin outside of a running [App], or to drive the persistence of a running one.
+//!
+//! This is useful to check a settings file in a test or a small CLI tool before
+//! sharing it, for example :
+//! ```no_run
+//! # use bevy_log_events::settings_io;
+//! # fn main() -> Result<(), bevy_log_events::LogEventsError> {
+//! let settings = settings_io::load("assets/debug_log_settings.ron")?;
+//! assert_eq!(settings_io::round_trip(&settings)?, settings);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    any::type_name,
+    collections::{BTreeMap, BTreeSet},
+    fs::{create_dir_all, read_to_string, rename, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use bevy::{log::Level, prelude::*};
+
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    systems::{apply_settings, current_settings, LogSettingsIds},
+    utils::{
+        deserialize_level, deserialize_optional_level, serialize_level, serialize_optional_level,
+        with_level_style,
+    },
+    ChannelSettings, EventSettings, FilterPreset, LevelPalette, LogEventsError,
+    LogEventsPluginSettings, SettingsFormat, SoundCue,
+};
+
+/// The content of a settings file.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct LoggedEventsSettings {
+    /// See [enabled](crate::LogEventsPluginSettings::enabled).
+    pub plugin_enabled: bool,
+    /// See [min_level](crate::LogEventsPluginSettings::min_level).
+    #[serde(
+        default = "default_min_level",
+        serialize_with = "serialize_level",
+        deserialize_with = "deserialize_level"
+    )]
+    pub min_level: Level,
+    /// See [channels](crate::LogEventsPluginSettings::channels).
+    #[serde(default)]
+    pub channels: BTreeMap<String, ChannelSettings>,
+    /// See [templates](crate::LogEventsPluginSettings::templates).
+    #[serde(default)]
+    pub templates: BTreeMap<String, String>,
+    /// See [entity_filters](crate::LogEventsPluginSettings::entity_filters).
+    #[serde(default)]
+    pub entity_filters: BTreeMap<String, String>,
+    /// See [sounds](crate::LogEventsPluginSettings::sounds).
+    #[serde(default)]
+    pub sounds: BTreeMap<String, SoundCue>,
+    /// See [output_files](crate::LogEventsPluginSettings::output_files).
+    #[serde(default)]
+    pub output_files: BTreeMap<String, PathBuf>,
+    /// See [pinned](crate::LogEventsPluginSettings::pinned).
+    #[serde(default)]
+    pub pinned: BTreeSet<String>,
+    /// See [favorites](crate::LogEventsPluginSettings::favorites).
+    #[serde(default)]
+    pub favorites: BTreeSet<String>,
+    /// See [filter_presets](crate::LogEventsPluginSettings::filter_presets).
+    #[serde(default)]
+    pub filter_presets: BTreeMap<String, FilterPreset>,
+    /// See [watch](crate::LogEventsPluginSettings::watch).
+    #[serde(default)]
+    pub watch: Vec<String>,
+    /// See [renames](crate::LogEventsPluginSettings::renames).
+    #[serde(default)]
+    pub renames: BTreeMap<String, String>,
+    /// Where the settings window was and whether it was open.
+    #[serde(default)]
+    pub window: WindowLayout,
+    /// See [level_palette](crate::LogEventsPluginSettings::level_palette).
+    #[serde(default)]
+    pub level_palette: LevelPalette,
+    /// The [EventSettings] of each event, by name.
+    pub events_settings: BTreeMap<String, EventSettings>,
+    /// The settings applied over the other ones on some platforms, by platform name. See
+    /// [current_platforms].
+    #[serde(default)]
+    pub platforms: BTreeMap<String, PlatformSettings>,
+}
+
+/// Where the settings window is and whether it is open, so it is shown the same way on
+/// the next run.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default, Debug)]
+pub struct WindowLayout {
+    /// See [show_window](crate::LogEventsPluginSettings::show_window).
+    pub open: bool,
+    /// The position of the top left corner of the window, in logical pixels.
+    pub position: Option<(f32, f32)>,
+    /// The size of the content of the window, in logical pixels.
+    pub size: Option<(f32, f32)>,
+}
+
+/// The content of a settings file used as the personal overlay of a base file, see
+/// [with_base](crate::LogEventsPlugin::with_base). It only holds the values that differ
+/// from the base: the other values are [None] and the other entries of the maps are
+/// missing, so the changes made later to the base are not hidden by the overlay.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+pub struct OverlaySettings {
+    /// See [enabled](crate::LogEventsPluginSettings::enabled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_enabled: Option<bool>,
+    /// See [min_level](crate::LogEventsPluginSettings::min_level).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_level",
+        deserialize_with = "deserialize_optional_level"
+    )]
+    pub min_level: Option<Level>,
+    /// See [channels](crate::LogEventsPluginSettings::channels).
+    #[serde(default)]
+    pub channels: BTreeMap<String, ChannelSettings>,
+    /// See [templates](crate::LogEventsPluginSettings::templates).
+    #[serde(default)]
+    pub templates: BTreeMap<String, String>,
+    /// See [entity_filters](crate::LogEventsPluginSettings::entity_filters).
+    #[serde(default)]
+    pub entity_filters: BTreeMap<String, String>,
+    /// See [sounds](crate::LogEventsPluginSettings::sounds).
+    #[serde(default)]
+    pub sounds: BTreeMap<String, SoundCue>,
+    /// See [output_files](crate::LogEventsPluginSettings::output_files).
+    #[serde(default)]
+    pub output_files: BTreeMap<String, PathBuf>,
+    /// See [pinned](crate::LogEventsPluginSettings::pinned).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<BTreeSet<String>>,
+    /// See [favorites](crate::LogEventsPluginSettings::favorites).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favorites: Option<BTreeSet<String>>,
+    /// See [filter_presets](crate::LogEventsPluginSettings::filter_presets).
+    #[serde(default)]
+    pub filter_presets: BTreeMap<String, FilterPreset>,
+    /// See [watch](crate::LogEventsPluginSettings::watch).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch: Option<Vec<String>>,
+    /// See [renames](crate::LogEventsPluginSettings::renames).
+    #[serde(default)]
+    pub renames: BTreeMap<String, String>,
+    /// See [window](LoggedEventsSettings::window).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<WindowLayout>,
+    /// See [level_palette](LoggedEventsSettings::level_palette).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level_palette: Option<LevelPalette>,
+    /// The [EventSettings] of each event, by name.
+    #[serde(default)]
+    pub events_settings: BTreeMap<String, EventSettings>,
+    /// See [platforms](LoggedEventsSettings::platforms).
+    #[serde(default)]
+    pub platforms: BTreeMap<String, PlatformSettings>,
+}
+
+/// The settings of a platform section of a settings file, replacing the other settings
+/// when the program runs on this platform. The settings missing from the section are
+/// left as they are.
+///
+/// As an example, to only log the touch events on mobile :
+/// ```ron
+/// platforms: {
+///     "desktop": PlatformSettings(
+///         events_settings: {
+///             "bevy_input::touch::TouchInput": EventSettings(
+///                 enabled: false,
+///                 format: "Compact",
+///                 level: "INFO",
+///             ),
+///         },
+///     ),
+/// },
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default, Debug)]
+pub struct PlatformSettings {
+    /// See [enabled](crate::LogEventsPluginSettings::enabled).
+    #[serde(default)]
+    pub plugin_enabled: Option<bool>,
+    /// See [min_level](crate::LogEventsPluginSettings::min_level).
+    #[serde(
+        default,
+        serialize_with = "serialize_optional_level",
+        deserialize_with = "deserialize_optional_level"
+    )]
+    pub min_level: Option<Level>,
+    /// See [channels](crate::LogEventsPluginSettings::channels).
+    #[serde(default)]
+    pub channels: BTreeMap<String, ChannelSettings>,
+    /// The [EventSettings] of each event, by name.
+    #[serde(default)]
+    pub events_settings: BTreeMap<String, EventSettings>,
+}
+
+impl LoggedEventsSettings {
+    /// Move the settings saved under the old names of [renames](Self::renames) to their
+    /// new names. This is done by [load] and [from_ron].
+    ///
+    /// The settings already saved under a new name are kept over the ones of its old name.
+    pub fn apply_renames(&mut self) {
+        for (old, new) in &self.renames {
+            if let Some(settings) = self.events_settings.remove(old) {
+                self.events_settings.entry(new.clone()).or_insert(settings);
+            }
+            if let Some(template) = self.templates.remove(old) {
+                self.templates.entry(new.clone()).or_insert(template);
+            }
+            if let Some(filter) = self.entity_filters.remove(old) {
+                self.entity_filters.entry(new.clone()).or_insert(filter);
+            }
+            if let Some(sound) = self.sounds.remove(old) {
+                self.sounds.entry(new.clone()).or_insert(sound);
+            }
+            if let Some(path) = self.output_files.remove(old) {
+                self.output_files.entry(new.clone()).or_insert(path);
+            }
+            if self.pinned.remove(old) {
+                self.pinned.insert(new.clone());
+            }
+            if self.favorites.remove(old) {
+                self.favorites.insert(new.clone());
+            }
+            for platform in self.platforms.values_mut() {
+                if let Some(settings) = platform.events_settings.remove(old) {
+                    platform
+                        .events_settings
+                        .entry(new.clone())
+                        .or_insert(settings);
+                }
+            }
+        }
+    }
+
+    /// These settings with the sections of [platforms](Self::platforms) named in
+    /// `platforms` applied over them, in order. This is done by the plugin with the
+    /// [current_platforms] when it loads a settings file.
+    pub fn apply_platforms(&self, platforms: &[&str]) -> LoggedEventsSettings {
+        let mut applied = self.clone();
+        for platform in platforms
+            .iter()
+            .filter_map(|name| self.platforms.get(*name))
+        {
+            if let Some(enabled) = platform.plugin_enabled {
+                applied.plugin_enabled = enabled;
+            }
+            if let Some(min_level) = platform.min_level {
+                applied.min_level = min_level;
+            }
+            applied.channels.extend(platform.channels.clone());
+            applied
+                .events_settings
+                .extend(platform.events_settings.clone());
+        }
+        applied
+    }
+
+    /// The reverse of [apply_platforms](Self::apply_platforms): the settings to write in
+    /// the file these settings were read from so it gives `applied` once the sections of
+    /// `platforms` are applied.
+    ///
+    /// The values replaced by a section are written in the last section replacing them,
+    /// so the changes made on a platform stay on this platform. The other values are
+    /// taken from `applied`.
+    pub fn store_platforms(
+        &self,
+        applied: &LoggedEventsSettings,
+        platforms: &[&str],
+    ) -> LoggedEventsSettings {
+        let mut stored = LoggedEventsSettings {
+            platforms: self.platforms.clone(),
+            ..applied.clone()
+        };
+        let (mut enabled_stored, mut level_stored) = (false, false);
+        let mut channels_stored = BTreeSet::new();
+        let mut events_stored = BTreeSet::new();
+        for name in platforms.iter().rev() {
+            let Some(platform) = stored.platforms.get_mut(*name) else {
+                continue;
+            };
+            if let Some(enabled) = platform.plugin_enabled.as_mut().filter(|_| !enabled_stored) {
+                *enabled = applied.plugin_enabled;
+                stored.plugin_enabled = self.plugin_enabled;
+                enabled_stored = true;
+            }
+            if let Some(min_level) = platform.min_level.as_mut().filter(|_| !level_stored) {
+                *min_level = applied.min_level;
+                stored.min_level = self.min_level;
+                level_stored = true;
+            }
+            store_platform_values(
+                &mut platform.channels,
+                &mut channels_stored,
+                &mut stored.channels,
+                &self.channels,
+            );
+            store_platform_values(
+                &mut platform.events_settings,
+                &mut events_stored,
+                &mut stored.events_settings,
+                &self.events_settings,
+            );
+        }
+        stored
+    }
+
+    /// These settings with the user overlay `overlay` applied over them. The entries of
+    /// the maps of `overlay`, like its [events_settings](Self::events_settings), replace or
+    /// are added to the ones of these settings, and its other values replace theirs when
+    /// they are set.
+    ///
+    /// This is done by the plugin when it is built
+    /// [with_base](crate::LogEventsPlugin::with_base).
+    pub fn apply_overlay(&self, overlay: &OverlaySettings) -> LoggedEventsSettings {
+        fn extended<V: Clone>(
+            base: &BTreeMap<String, V>,
+            overlay: &BTreeMap<String, V>,
+        ) -> BTreeMap<String, V> {
+            let mut extended = base.clone();
+            extended.extend(overlay.clone());
+            extended
+        }
+        fn or<V: Clone>(overlay: &Option<V>, base: &V) -> V {
+            overlay.clone().unwrap_or_else(|| base.clone())
+        }
+        let mut applied = LoggedEventsSettings {
+            plugin_enabled: or(&overlay.plugin_enabled, &self.plugin_enabled),
+            min_level: or(&overlay.min_level, &self.min_level),
+            pinned: or(&overlay.pinned, &self.pinned),
+            favorites: or(&overlay.favorites, &self.favorites),
+            watch: or(&overlay.watch, &self.watch),
+            window: or(&overlay.window, &self.window),
+            level_palette: or(&overlay.level_palette, &self.level_palette),
+            channels: extended(&self.channels, &overlay.channels),
+            templates: extended(&self.templates, &overlay.templates),
+            entity_filters: extended(&self.entity_filters, &overlay.entity_filters),
+            sounds: extended(&self.sounds, &overlay.sounds),
+            output_files: extended(&self.output_files, &overlay.output_files),
+            filter_presets: extended(&self.filter_presets, &overlay.filter_presets),
+            renames: extended(&self.renames, &overlay.renames),
+            events_settings: extended(&self.events_settings, &overlay.events_settings),
+            platforms: extended(&self.platforms, &overlay.platforms),
+        };
+        applied.apply_renames();
+        applied
+    }
+
+    /// The reverse of [apply_overlay](Self::apply_overlay): the overlay to apply over
+    /// `base` to get these settings. Only the values and the entries of the maps that
+    /// differ from `base` are kept, so the overlay stays small.
+    ///
+    /// The entries of `base` removed from these settings can not be told apart, so they
+    /// come back once the overlay is applied.
+    pub fn store_overlay(&self, base: &LoggedEventsSettings) -> OverlaySettings {
+        fn differences<V: Clone + PartialEq>(
+            map: &BTreeMap<String, V>,
+            base: &BTreeMap<String, V>,
+        ) -> BTreeMap<String, V> {
+            map.iter()
+                .filter(|(name, value)| base.get(*name) != Some(*value))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect()
+        }
+        fn changed<V: Clone + PartialEq>(value: &V, base: &V) -> Option<V> {
+            (value != base).then(|| value.clone())
+        }
+        OverlaySettings {
+            plugin_enabled: changed(&self.plugin_enabled, &base.plugin_enabled),
+            min_level: changed(&self.min_level, &base.min_level),
+            pinned: changed(&self.pinned, &base.pinned),
+            favorites: changed(&self.favorites, &base.favorites),
+            watch: changed(&self.watch, &base.watch),
+            window: changed(&self.window, &base.window),
+            level_palette: changed(&self.level_palette, &base.level_palette),
+            channels: differences(&self.channels, &base.channels),
+            templates: differences(&self.templates, &base.templates),
+            entity_filters: differences(&self.entity_filters, &base.entity_filters),
+            sounds: differences(&self.sounds, &base.sounds),
+            output_files: differences(&self.output_files, &base.output_files),
+            filter_presets: differences(&self.filter_presets, &base.filter_presets),
+            renames: differences(&self.renames, &base.renames),
+            events_settings: differences(&self.events_settings, &base.events_settings),
+            platforms: differences(&self.platforms, &base.platforms),
+        }
+    }
+}
+
+/// Move the values of `applied` replaced by the section `platform` into it and put back
+/// their values from `base` in `applied`, unless they were already `stored` in a later
+/// section.
+fn store_platform_values<V: Clone>(
+    platform: &mut BTreeMap<String, V>,
+    stored: &mut BTreeSet<String>,
+    applied: &mut BTreeMap<String, V>,
+    base: &BTreeMap<String, V>,
+) {
+    for (name, value) in platform.iter_mut() {
+        if !stored.insert(name.clone()) {
+            continue;
+        }
+        if let Some(applied) = applied.get(name) {
+            *value = applied.clone();
+        }
+        match base.get(name) {
+            Some(base) => applied.insert(name.clone(), base.clone()),
+            None => applied.remove(name),
+        };
+    }
+}
+
+/// The names of the [platforms](LoggedEventsSettings::platforms) sections applied on the
+/// platform the program runs on, from the least to the most specific: `"desktop"` or
+/// `"mobile"` followed by `"windows"`, `"macos"`, `"linux"`, `"android"` or `"ios"`, or
+/// only `"wasm"` on the web.
+pub fn current_platforms() -> &'static [&'static str] {
+    if cfg!(target_arch = "wasm32") {
+        &["wasm"]
+    } else if cfg!(target_os = "android") {
+        &["mobile", "android"]
+    } else if cfg!(target_os = "ios") {
+        &["mobile", "ios"]
+    } else if cfg!(windows) {
+        &["desktop", "windows"]
+    } else if cfg!(target_os = "macos") {
+        &["desktop", "macos"]
+    } else if cfg!(target_os = "linux") {
+        &["desktop", "linux"]
+    } else {
+        &["desktop"]
+    }
+}
+
+fn default_min_level() -> Level {
+    Level::TRACE
+}
+
+/// Read the settings stored in the file at `path`, in the [SettingsFormat] of its
+/// extension. Their [platforms](LoggedEventsSettings::platforms) sections are not applied.
+pub fn load(path: impl AsRef<Path>) -> Result<LoggedEventsSettings, LogEventsError> {
+    let format = SettingsFormat::from_path(&path);
+    load_as(path, format)
+}
+
+/// Read the settings stored in `format` in the file at `path`.
+pub fn load_as(
+    path: impl AsRef<Path>,
+    format: SettingsFormat,
+) -> Result<LoggedEventsSettings, LogEventsError> {
+    deserialize(&read_to_string(path)?, format)
+}
+
+/// Read the overlay stored in `format` in the file at `path`, see [OverlaySettings].
+pub fn load_overlay_as(
+    path: impl AsRef<Path>,
+    format: SettingsFormat,
+) -> Result<OverlaySettings, LogEventsError> {
+    from_str_in(&read_to_string(path)?, format)
+}
+
+/// Write `settings` in the file at `path`, in the [SettingsFormat] of its extension,
+/// creating its parent directories if needed.
+///
+/// The settings are written in a temporary file first which then replaces the one at
+/// `path`, so several programs saving at the same time can not leave it half written.
+pub fn save(path: impl AsRef<Path>, settings: &LoggedEventsSettings) -> Result<(), LogEventsError> {
+    let format = SettingsFormat::from_path(&path);
+    save_as(path, settings, format)
+}
+
+/// Write `settings` in `format` in the file at `path`, like [save].
+pub fn save_as(
+    path: impl AsRef<Path>,
+    settings: &LoggedEventsSettings,
+    format: SettingsFormat,
+) -> Result<(), LogEventsError> {
+    write_file(path.as_ref(), &serialize(settings, format)?)
+}
+
+/// Write `overlay` in `format` in the file at `path`, like [save].
+pub fn save_overlay_as(
+    path: impl AsRef<Path>,
+    overlay: &OverlaySettings,
+    format: SettingsFormat,
+) -> Result<(), LogEventsError> {
+    write_file(path.as_ref(), &to_string_in(overlay, format)?)
+}
+
+fn write_file(path: &Path, serialized: &str) -> Result<(), LogEventsError> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(format!(".{}.tmp", std::process::id()));
+    File::create(&temp_path)?.write_all(serialized.as_bytes())?;
+    rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Merge the settings `ours` and `theirs` that were both made from `base`: the values
+/// changed in `ours` are kept and the others are taken from `theirs`.
+///
+/// The events missing from `ours` are taken from `theirs`, as they are usually events
+/// that were not registered rather than removed ones.
+pub fn merge(
+    base: &LoggedEventsSettings,
+    ours: &LoggedEventsSettings,
+    theirs: &LoggedEventsSettings,
+) -> LoggedEventsSettings {
+    let mut events_settings = theirs.events_settings.clone();
+    for (name, settings) in &ours.events_settings {
+        if base.events_settings.get(name) != Some(settings) {
+            events_settings.insert(name.clone(), settings.clone());
+        }
+    }
+    LoggedEventsSettings {
+        plugin_enabled: merge_value(
+            &base.plugin_enabled,
+            &ours.plugin_enabled,
+            &theirs.plugin_enabled,
+        ),
+        min_level: merge_value(&base.min_level, &ours.min_level, &theirs.min_level),
+        channels: merge_map(&base.channels, &ours.channels, &theirs.channels),
+        templates: merge_map(&base.templates, &ours.templates, &theirs.templates),
+        entity_filters: merge_map(
+            &base.entity_filters,
+            &ours.entity_filters,
+            &theirs.entity_filters,
+        ),
+        sounds: merge_map(&base.sounds, &ours.sounds, &theirs.sounds),
+        output_files: merge_map(&base.output_files, &ours.output_files, &theirs.output_files),
+        pinned: merge_set(&base.pinned, &ours.pinned, &theirs.pinned),
+        favorites: merge_set(&base.favorites, &ours.favorites, &theirs.favorites),
+        filter_presets: merge_map(
+            &base.filter_presets,
+            &ours.filter_presets,
+            &theirs.filter_presets,
+        ),
+        watch: merge_value(&base.watch, &ours.watch, &theirs.watch),
+        renames: merge_map(&base.renames, &ours.renames, &theirs.renames),
+        window: merge_value(&base.window, &ours.window, &theirs.window),
+        level_palette: merge_value(
+            &base.level_palette,
+            &ours.level_palette,
+            &theirs.level_palette,
+        ),
+        events_settings,
+        platforms: merge_map(&base.platforms, &ours.platforms, &theirs.platforms),
+    }
+}
+
+fn merge_value<T: PartialEq + Clone>(base: &T, ours: &T, theirs: &T) -> T {
+    if ours != base {
+        ours.clone()
+    } else {
+        theirs.clone()
+    }
+}
+
+fn merge_map<V: PartialEq + Clone>(
+    base: &BTreeMap<String, V>,
+    ours: &BTreeMap<String, V>,
+    theirs: &BTreeMap<String, V>,
+) -> BTreeMap<String, V> {
+    let mut merged = theirs.clone();
+    for name in base.keys().chain(ours.keys()) {
+        match (base.get(name), ours.get(name)) {
+            (base, Some(ours)) if base != Some(ours) => {
+                merged.insert(name.clone(), ours.clone());
+            }
+            (Some(_), None) => {
+                merged.remove(name);
+            }
+            _ => {}
+        }
+    }
+    merged
+}
+
+fn merge_set(
+    base: &BTreeSet<String>,
+    ours: &BTreeSet<String>,
+    theirs: &BTreeSet<String>,
+) -> BTreeSet<String> {
+    let mut merged = theirs.clone();
+    merged.extend(ours.difference(base).cloned());
+    for removed in base.difference(ours) {
+        merged.remove(removed);
+    }
+    merged
+}
+
+/// Read settings from their representation in `format`.
+pub fn deserialize(
+    serialized: &str,
+    format: SettingsFormat,
+) -> Result<LoggedEventsSettings, LogEventsError> {
+    let mut settings: LoggedEventsSettings = from_str_in(serialized, format)?;
+    settings.apply_renames();
+    Ok(settings)
+}
+
+/// Returns the representation of `settings` in `format`, as it would be written in a file.
+pub fn serialize(
+    settings: &LoggedEventsSettings,
+    format: SettingsFormat,
+) -> Result<String, LogEventsError> {
+    to_string_in(settings, format)
+}
+
+fn from_str_in<T: DeserializeOwned>(
+    serialized: &str,
+    format: SettingsFormat,
+) -> Result<T, LogEventsError> {
+    Ok(match format {
+        SettingsFormat::Ron => ron::from_str(serialized)?,
+        SettingsFormat::Json => serde_json::from_str(serialized)?,
+        SettingsFormat::Toml => toml::from_str(serialized)?,
+    })
+}
+
+fn to_string_in<T: Serialize>(value: &T, format: SettingsFormat) -> Result<String, LogEventsError> {
+    Ok(match format {
+        SettingsFormat::Ron => {
+            let config = PrettyConfig::default().struct_names(true);
+            ron::ser::to_string_pretty(value, config)?
+        }
+        SettingsFormat::Json => serde_json::to_string_pretty(value)?,
+        SettingsFormat::Toml => toml::to_string_pretty(value)?,
+    })
+}
+
+/// Read settings from their RON representation.
+pub fn from_ron(ron: &str) -> Result<LoggedEventsSettings, LogEventsError> {
+    deserialize(ron, SettingsFormat::Ron)
+}
+
+/// Returns the RON representation of `settings`, as it would be written in a file.
+pub fn to_ron(settings: &LoggedEventsSettings) -> Result<String, LogEventsError> {
+    to_string_in(settings, SettingsFormat::Ron)
+}
+
+/// Serialize then deserialize `settings`. The result should be equal to `settings`.
+pub fn round_trip(settings: &LoggedEventsSettings) -> Result<LoggedEventsSettings, LogEventsError> {
+    from_ron(&to_ron(settings)?)
+}
+
+/// Read the settings file at `path` like [load] and check that writing them back gives
+/// the same settings, returning a [LogEventsError::RoundTrip] if it does not. Meant to
+/// validate a settings file in a test or a tool before sharing it.
+///
+/// ```no_run
+/// # use bevy_log_events::settings_io;
+/// #[test]
+/// fn debug_settings_are_valid() {
+///     settings_io::check_file("assets/log_settings.ron").unwrap();
+/// }
+/// ```
+pub fn check_file(path: impl AsRef<Path>) -> Result<LoggedEventsSettings, LogEventsError> {
+    let path = path.as_ref();
+    let format = SettingsFormat::from_path(path);
+    let settings = load_as(path, format)?;
+    if deserialize(&serialize(&settings, format)?, format)? != settings {
+        return Err(LogEventsError::RoundTrip(path.to_path_buf()));
+    }
+    Ok(settings)
+}
+
+/// Write the current settings of the plugin running in `world` in the file at `path`.
+pub fn save_world(world: &World, path: impl AsRef<Path>) -> Result<(), LogEventsError> {
+    check_plugin_resources(world)?;
+    let style = world.resource::<LogEventsPluginSettings>().level_style;
+    with_level_style(style, || save(path, &current_settings::<()>(world)))
+}
+
+/// Read the settings stored in the file at `path` and apply them to the plugin running
+/// in `world`. The registered events missing from the file are reset to their default.
+pub fn load_world(world: &mut World, path: impl AsRef<Path>) -> Result<(), LogEventsError> {
+    check_plugin_resources(world)?;
+    apply_settings::<()>(world, load(path)?);
+    Ok(())
+}
+
+fn check_plugin_resources(world: &World) -> Result<(), LogEventsError> {
+    if !world.contains_resource::<LogEventsPluginSettings>() {
+        return Err(LogEventsError::MissingResource(type_name::<
+            LogEventsPluginSettings,
+        >()));
+    }
+    if !world.contains_resource::<LogSettingsIds>() {
+        return Err(LogEventsError::MissingResource(
+            type_name::<LogSettingsIds>(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(ron: &str) -> LoggedEventsSettings {
+        from_ron(ron).unwrap()
+    }
+
+    fn sample() -> LoggedEventsSettings {
+        settings(
+            r#"(
+                plugin_enabled: true,
+                min_level: "DEBUG",
+                templates: {"PlayerMoved": "{name} moved"},
+                pinned: ["PlayerMoved"],
+                level_palette: Colorblind,
+                events_settings: {
+                    "PlayerMoved": (enabled: true, format: "Pretty", level: "WARN"),
+                    "EnemySpawned": (enabled: false, format: "Compact", level: "TRACE"),
+                    "EnemyHit": (enabled: true, format: "hp", level: "INFO"),
+                },
+                platforms: {"mobile": (plugin_enabled: Some(false))},
+            )"#,
+        )
+    }
+
+    #[test]
+    fn round_trip_in_every_format() {
+        let settings = sample();
+        assert_eq!(round_trip(&settings).unwrap(), settings);
+        for format in [
+            SettingsFormat::Ron,
+            SettingsFormat::Json,
+            SettingsFormat::Toml,
+        ] {
+            let serialized = serialize(&settings, format).unwrap();
+            assert_eq!(deserialize(&serialized, format).unwrap(), settings);
+        }
+    }
+
+    #[test]
+    fn invalid_settings_are_errors() {
+        assert!(matches!(
+            from_ron("(plugin_enabled: maybe)"),
+            Err(LogEventsError::Ron(_))
+        ));
+        assert!(matches!(
+            deserialize("{", SettingsFormat::Json),
+            Err(LogEventsError::Json(_))
+        ));
+        assert!(matches!(
+            load("does/not/exist.ron"),
+            Err(LogEventsError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn merge_changes() {
+        let base = sample();
+        let enabled =
+            |name: &str, settings: &LoggedEventsSettings| settings.events_settings[name].enabled;
+
+        let mut ours = base.clone();
+        ours.min_level = Level::ERROR;
+        ours.events_settings.get_mut("PlayerMoved").unwrap().enabled = false;
+        ours.pinned.clear();
+        let mut theirs = base.clone();
+        theirs.plugin_enabled = false;
+        theirs.level_palette = LevelPalette::Default;
+        theirs
+            .events_settings
+            .get_mut("EnemySpawned")
+            .unwrap()
+            .enabled = true;
+        theirs
+            .templates
+            .insert("EnemySpawned".into(), "{name}".into());
+
+        let merged = merge(&base, &ours, &theirs);
+        // Only changed by us.
+        assert_eq!(merged.min_level, Level::ERROR);
+        assert!(!enabled("PlayerMoved", &merged));
+        assert!(merged.pinned.is_empty());
+        // Only changed by them.
+        assert!(!merged.plugin_enabled);
+        assert_eq!(merged.level_palette, LevelPalette::Default);
+        assert!(enabled("EnemySpawned", &merged));
+        assert_eq!(merged.templates.len(), 2);
+    }
+
+    #[test]
+    fn merge_conflicts_keep_ours() {
+        let base = sample();
+        let mut ours = base.clone();
+        ours.min_level = Level::ERROR;
+        ours.events_settings.get_mut("PlayerMoved").unwrap().level = Level::INFO;
+        ours.templates.remove("PlayerMoved");
+        let mut theirs = base.clone();
+        theirs.min_level = Level::TRACE;
+        theirs.events_settings.get_mut("PlayerMoved").unwrap().level = Level::ERROR;
+        theirs
+            .templates
+            .insert("PlayerMoved".into(), "{name} ran".into());
+        // An event registered by the other program only.
+        theirs
+            .events_settings
+            .insert("ItemPicked".into(), EventSettings::default());
+
+        let merged = merge(&base, &ours, &theirs);
+        assert_eq!(merged.min_level, Level::ERROR);
+        assert_eq!(merged.events_settings["PlayerMoved"].level, Level::INFO);
+        assert!(!merged.templates.contains_key("PlayerMoved"));
+        assert!(merged.events_settings.contains_key("ItemPicked"));
+    }
+
+    #[test]
+    fn level_style_is_scoped() {
+        let settings = sample();
+        let numeric = with_level_style(crate::LevelStyle::Numeric, || {
+            // Another thread, like the one of another App, keeps its own style.
+            let other = std::thread::scope(|s| s.spawn(|| to_ron(&settings).unwrap()).join());
+            assert!(other.unwrap().contains("min_level: \"DEBUG\""));
+            to_ron(&settings).unwrap()
+        });
+        assert!(numeric.contains("min_level: 4"));
+        assert!(to_ron(&settings).unwrap().contains("min_level: \"DEBUG\""));
+        assert_eq!(from_ron(&numeric).unwrap(), settings);
+    }
+
+    #[test]
+    fn check_saved_file() {
+        let path =
+            std::env::temp_dir().join(format!("bevy_log_events_check_{}.json", std::process::id()));
+        save(&path, &sample()).unwrap();
+        assert_eq!(check_file(&path).unwrap(), sample());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn overlay_only_keeps_differences() {
+        let base = settings("(plugin_enabled: true, min_level: \"INFO\", events_settings: {})");
+        let mut current = base.clone();
+        current.min_level = Level::WARN;
+        let overlay = current.store_overlay(&base);
+        assert_eq!(
+            overlay,
+            OverlaySettings {
+                min_level: Some(Level::WARN),
+                ..default()
+            }
+        );
+        let serialized = to_string_in(&overlay, SettingsFormat::Ron).unwrap();
+        let overlay: OverlaySettings = from_str_in(&serialized, SettingsFormat::Ron).unwrap();
+        // A later change of the base is not hidden by the overlay.
+        let mut new_base = base.clone();
+        new_base.plugin_enabled = false;
+        let applied = new_base.apply_overlay(&overlay);
+        assert!(!applied.plugin_enabled);
+        assert_eq!(applied.min_level, Level::WARN);
+    }
+}
@@ -1,22 +1,80 @@
-use bevy::{log::Level, prelude::*};
-use bevy_egui::{egui, EguiContext, EguiPlugin};
+use std::{
+    any::{type_name, TypeId},
+    collections::BTreeSet,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
 
-use regex::Regex;
+use bevy::{
+    log::Level,
+    prelude::*,
+    reflect::{
+        DynamicEnum, DynamicTuple, DynamicVariant, Enum, NamedField, ReflectMut, TypeInfo, Typed,
+        VariantInfo,
+    },
+    window::PrimaryWindow,
+};
+use bevy_egui::{egui, EguiContext, EguiPlugin};
+use serde::Serialize;
 
 use crate::{
-    systems::LogSettingsIds, utils::get_log_settings_mut_by_id, EventSettings,
-    LogEventsPluginSettings,
+    diagnostics::MemoryUsage,
+    log_files::default_output_path,
+    settings_io::{self, LoggedEventsSettings},
+    systems::{
+        apply_settings, current_settings, soloed, toggle_solo, ComponentHistory, EventActivity,
+        LogSettingsIds, RegisteredEvent,
+    },
+    ui_model::EventFilter,
+    utils::{get_log_settings_by_id, get_log_settings_mut_by_id, short_type_name},
+    EventSettings, FilterPreset, LevelPalette, LogEventsError, LogEventsPluginSettings, LogFormat,
+    LogInstance, LogOutput, NameStyle, UiDensity, LOG_TARGET, MAX_PINNED,
 };
 
-pub(crate) fn plugin(app: &mut App) {
+pub(crate) fn plugin<I: LogInstance>(app: &mut App, add_egui: bool) {
     if !app.is_plugin_added::<EguiPlugin>() {
+        if !app.is_plugin_added::<WindowPlugin>() {
+            warn!(target: LOG_TARGET, "There is no WindowPlugin, the settings window will not be available. If you use one, add it before the LogEventsPlugin.");
+            return;
+        }
+        if !add_egui {
+            warn!(target: LOG_TARGET, "There is no EguiPlugin, the settings window will not be available. Add it before the LogEventsPlugin.");
+            return;
+        }
         app.add_plugins(EguiPlugin);
     }
-    app.insert_resource(LogEventsWindowState::default())
-        .add_systems(Update, show_settings_window);
+    app.world_mut()
+        .resource_mut::<LogEventsPluginSettings<I>>()
+        .window_available = true;
+    let settings_path = app
+        .world()
+        .resource::<LogEventsPluginSettings<I>>()
+        .saved_settings
+        .display()
+        .to_string();
+    app.insert_resource(LogEventsWindowState::<I> {
+        settings_path,
+        ..default()
+    })
+    .add_systems(
+        Update,
+        (
+            show_settings_window::<I>.run_if(window_shown::<I>),
+            show_overlay::<I>.run_if(overlay_shown::<I>),
+        ),
+    );
+    crate::log_viewer::plugin::<I>(app);
+}
+
+fn window_shown<I: LogInstance>(plugin_settings: Res<LogEventsPluginSettings<I>>) -> bool {
+    plugin_settings.show_window
+}
+
+fn overlay_shown<I: LogInstance>(plugin_settings: Res<LogEventsPluginSettings<I>>) -> bool {
+    plugin_settings.show_overlay && !plugin_settings.pinned.is_empty()
 }
 
-const ALL_LEVELS: [Level; 5] = [
+pub(crate) const ALL_LEVELS: [Level; 5] = [
     Level::ERROR,
     Level::WARN,
     Level::INFO,
@@ -24,18 +82,38 @@ const ALL_LEVELS: [Level; 5] = [
     Level::TRACE,
 ];
 
-fn level_color(level: Level) -> egui::Color32 {
+pub(crate) fn level_color(level: Level, palette: LevelPalette) -> egui::Color32 {
+    match palette {
+        LevelPalette::Default => match level {
+            Level::INFO => egui::Color32::from_rgb(45, 193, 40),
+            Level::WARN => egui::Color32::from_rgb(249, 201, 24),
+            Level::ERROR => egui::Color32::from_rgb(219, 23, 2),
+            Level::DEBUG => egui::Color32::from_rgb(49, 140, 231),
+            Level::TRACE => egui::Color32::from_rgb(189, 51, 164),
+        },
+        // The Okabe-Ito palette, distinguishable with the common color vision deficiencies.
+        LevelPalette::Colorblind => match level {
+            Level::INFO => egui::Color32::from_rgb(0, 158, 115),
+            Level::WARN => egui::Color32::from_rgb(240, 228, 66),
+            Level::ERROR => egui::Color32::from_rgb(213, 94, 0),
+            Level::DEBUG => egui::Color32::from_rgb(86, 180, 233),
+            Level::TRACE => egui::Color32::from_rgb(204, 121, 167),
+        },
+    }
+}
+
+fn level_description(level: Level) -> &'static str {
     match level {
-        Level::INFO => egui::Color32::from_rgb(45, 193, 40),
-        Level::WARN => egui::Color32::from_rgb(249, 201, 24),
-        Level::ERROR => egui::Color32::from_rgb(219, 23, 2),
-        Level::DEBUG => egui::Color32::from_rgb(49, 140, 231),
-        Level::TRACE => egui::Color32::from_rgb(189, 51, 164),
+        Level::ERROR => "Something failed",
+        Level::WARN => "Something may be wrong",
+        Level::INFO => "Useful information",
+        Level::DEBUG => "Lower priority information",
+        Level::TRACE => "Very verbose information",
     }
 }
 
-fn colored_text_level(level: Level) -> egui::RichText {
-    egui::RichText::new(level.as_str()).color(level_color(level))
+pub(crate) fn colored_text_level(level: Level, palette: LevelPalette) -> egui::RichText {
+    egui::RichText::new(level.as_str()).color(level_color(level, palette))
 }
 
 #[derive(Default, PartialEq, Clone, Copy)]
@@ -50,14 +128,6 @@ impl EnabledFilter {
     fn iter() -> impl Iterator<Item = Self> {
         [Self::All, Self::Enabled, Self::Disabled].into_iter()
     }
-
-    fn contains(&self, enabled: bool) -> bool {
-        match self {
-            EnabledFilter::All => true,
-            EnabledFilter::Enabled => enabled,
-            EnabledFilter::Disabled => !enabled,
-        }
-    }
 }
 
 impl std::fmt::Display for EnabledFilter {
@@ -79,65 +149,215 @@ enum LevelFilter {
 }
 
 impl LevelFilter {
-    fn contains(&self, level: Level) -> bool {
+    fn to_label(self, palette: LevelPalette) -> egui::RichText {
         match self {
-            LevelFilter::All => true,
-            LevelFilter::Level(lvl) => *lvl == level,
+            LevelFilter::All => "All".into(),
+            LevelFilter::Level(level) => colored_text_level(level, palette),
         }
     }
+}
 
-    fn to_label(self) -> egui::RichText {
+#[derive(Default, PartialEq, Clone, Copy)]
+enum SortMode {
+    #[default]
+    Alphabetical,
+    Registration,
+    RecentlyRegistered,
+    RecentlyActive,
+    Level,
+}
+
+impl SortMode {
+    fn iter() -> impl Iterator<Item = Self> {
+        [
+            Self::Alphabetical,
+            Self::Registration,
+            Self::RecentlyRegistered,
+            Self::RecentlyActive,
+            Self::Level,
+        ]
+        .into_iter()
+    }
+
+    fn sort(&self, world: &World, entries: &mut [(&String, &RegisteredEvent)]) {
         match self {
-            LevelFilter::All => "All".into(),
-            LevelFilter::Level(level) => colored_text_level(level),
+            SortMode::Alphabetical => {}
+            SortMode::Registration => entries.sort_by_key(|(_, registered)| registered.index),
+            SortMode::RecentlyRegistered => {
+                entries.sort_by_key(|(_, registered)| std::cmp::Reverse(registered.index));
+            }
+            SortMode::RecentlyActive => entries.sort_by_cached_key(|(_, registered)| {
+                std::cmp::Reverse(registered.stats.lock().last_logged)
+            }),
+            SortMode::Level => entries.sort_by_key(|(_, registered)| {
+                get_log_settings_by_id(world, &registered.id).map(|settings| settings.level)
+            }),
         }
     }
 }
 
-#[derive(Default, Resource)]
-pub(crate) struct LogEventsWindowState {
+impl std::fmt::Display for SortMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            SortMode::Alphabetical => "Alphabetical",
+            SortMode::Registration => "Registration order",
+            SortMode::RecentlyRegistered => "Recently registered",
+            SortMode::RecentlyActive => "Recently active",
+            SortMode::Level => "Level",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// The state of the settings window of the instance `I` of the plugin.
+#[derive(Resource)]
+pub(crate) struct LogEventsWindowState<I = ()> {
+    pub(crate) settings_path: String,
+    file_status: Option<Result<String, String>>,
     name_filter: String,
     case_sensitive: bool,
     use_regex: bool,
+    use_expression: bool,
     enabled_filter: EnabledFilter,
     level_filter: LevelFilter,
-    regex: Option<Regex>,
+    sort_mode: SortMode,
+    filter: EventFilter,
     shown: usize,
+    preset_name: String,
+    undo_stack: Vec<LoggedEventsSettings>,
+    redo_stack: Vec<LoggedEventsSettings>,
+    confirm_reset_all: bool,
+    /// The events that differed from the settings file when the changes from it were last
+    /// listed, with their settings in the file, see [file_changes].
+    file_changes: Option<Vec<(String, Option<EventSettings>)>>,
+    /// The position among the shown events of the one selected with the keyboard.
+    selected_row: Option<usize>,
+    /// The events selected by clicking their name, edited at once by [selection_ui].
+    selection: BTreeSet<String>,
+    /// The event clicked last, where the range selected with shift starts.
+    selection_anchor: Option<String>,
+    _instance: PhantomData<fn() -> I>,
 }
 
-impl LogEventsWindowState {
-    fn name_contains_filter(&self, name: &str) -> bool {
-        let (name, filter) = if self.case_sensitive {
-            (name.to_string(), self.name_filter.clone())
-        } else {
-            (name.to_lowercase(), self.name_filter.to_lowercase())
-        };
-        if self.use_regex {
-            self.regex.as_ref().map_or(false, |re| re.is_match(&name))
-        } else {
-            name.contains(&filter)
+impl<I> Default for LogEventsWindowState<I> {
+    fn default() -> Self {
+        Self {
+            settings_path: String::new(),
+            file_status: None,
+            name_filter: String::new(),
+            case_sensitive: false,
+            use_regex: false,
+            use_expression: false,
+            enabled_filter: default(),
+            level_filter: default(),
+            sort_mode: default(),
+            filter: default(),
+            shown: 0,
+            preset_name: String::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            confirm_reset_all: false,
+            file_changes: None,
+            selected_row: None,
+            selection: BTreeSet::new(),
+            selection_anchor: None,
+            _instance: PhantomData,
         }
     }
+}
 
-    fn update_regex(&mut self) {
-        if self.use_regex {
-            let re = if self.case_sensitive {
-                self.name_filter.clone()
-            } else {
-                self.name_filter.to_lowercase()
-            };
-            self.regex = Regex::new(&re).ok();
+impl<I> LogEventsWindowState<I> {
+    /// Compile the filters again after they were edited.
+    fn update_filter(&mut self) {
+        self.filter = EventFilter::new(&self.preset());
+    }
+
+    fn preset(&self) -> FilterPreset {
+        FilterPreset {
+            name_filter: self.name_filter.clone(),
+            case_sensitive: self.case_sensitive,
+            use_regex: self.use_regex,
+            use_expression: self.use_expression,
+            enabled: match self.enabled_filter {
+                EnabledFilter::All => None,
+                EnabledFilter::Enabled => Some(true),
+                EnabledFilter::Disabled => Some(false),
+            },
+            level: match self.level_filter {
+                LevelFilter::All => None,
+                LevelFilter::Level(level) => Some(level),
+            },
+        }
+    }
+
+    fn apply_preset(&mut self, preset: &FilterPreset) {
+        self.name_filter = preset.name_filter.clone();
+        self.case_sensitive = preset.case_sensitive;
+        self.use_regex = preset.use_regex;
+        self.use_expression = preset.use_expression;
+        self.enabled_filter = match preset.enabled {
+            None => EnabledFilter::All,
+            Some(true) => EnabledFilter::Enabled,
+            Some(false) => EnabledFilter::Disabled,
+        };
+        self.level_filter = preset.level.map_or(LevelFilter::All, LevelFilter::Level);
+        self.update_filter();
+    }
+
+    /// The settings snapshots kept to undo and redo the changes.
+    pub(crate) fn history(&self) -> impl Iterator<Item = &LoggedEventsSettings> {
+        self.undo_stack.iter().chain(&self.redo_stack)
+    }
+
+    /// Update the [selection](Self::selection) after the name of the event `name` was
+    /// clicked with `modifiers` held, `shown` being the names of the events shown in order.
+    fn click_row(&mut self, name: &str, modifiers: egui::Modifiers, shown: &[String]) {
+        let position = |name: &str| shown.iter().position(|shown| shown == name);
+        if modifiers.shift {
+            if let (Some(start), Some(end)) = (
+                self.selection_anchor.as_deref().and_then(position),
+                position(name),
+            ) {
+                if !modifiers.command {
+                    self.selection.clear();
+                }
+                self.selection
+                    .extend(shown[start.min(end)..=start.max(end)].iter().cloned());
+                return;
+            }
+        }
+        if modifiers.command {
+            if !self.selection.remove(name) {
+                self.selection.insert(name.to_string());
+            }
+        } else if self.selection.len() == 1 && self.selection.contains(name) {
+            self.selection.clear();
         } else {
-            self.regex = None;
+            self.selection.clear();
+            self.selection.insert(name.to_string());
         }
+        self.selection_anchor = Some(name.to_string());
     }
 
-    fn must_show(&self, log_settings: &EventSettings) -> bool {
-        self.enabled_filter.contains(log_settings.enabled)
-            && self.level_filter.contains(log_settings.level)
+    fn record(&mut self, previous: LoggedEventsSettings) {
+        if self.undo_stack.len() == MAX_UNDO {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(previous);
+        self.redo_stack.clear();
     }
 }
 
+const MAX_UNDO: usize = 100;
+
+/// The value given to an optional float setting when it is enabled, they are periods in
+/// seconds like the one of the digest mode.
+const DEFAULT_PERIOD: f32 = 10.;
+
+/// The value given to an optional integer setting when it is enabled, they are counts like
+/// the maximum number of logs.
+const DEFAULT_COUNT: u32 = 100;
+
 macro_rules! selectable_label_switch {
     ($switch:expr, $ui:expr, $label:expr, $hover:expr) => {{
         let current = $switch;
@@ -151,26 +371,1161 @@ macro_rules! selectable_label_switch {
     }};
 }
 
-const WINDOW_NAME: &str = "Logged Events Settings";
+pub(crate) const WINDOW_NAME: &str = "Logged Events Settings";
+
+/// The title of the windows of the instance `I` of the plugin: `name` for the default
+/// instance, or else `name` followed by the short name of `I`, like
+/// `"Logged Events Settings (NetworkLogs)"`.
+pub(crate) fn instance_title<I: LogInstance>(name: &str) -> String {
+    if TypeId::of::<I>() == TypeId::of::<()>() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, short_type_name(type_name::<I>()))
+    }
+}
+
+fn load_settings_file<I: LogInstance>(
+    world: &mut World,
+    path: &Path,
+) -> Result<(), LogEventsError> {
+    settings_io::load_world(world, path)?;
+    world
+        .resource_mut::<LogEventsPluginSettings<I>>()
+        .saved_settings = path.to_path_buf();
+    Ok(())
+}
+
+fn save_settings_file<I: LogInstance>(
+    world: &mut World,
+    path: &Path,
+) -> Result<(), LogEventsError> {
+    settings_io::save_world(world, path)?;
+    world
+        .resource_mut::<LogEventsPluginSettings<I>>()
+        .saved_settings = path.to_path_buf();
+    Ok(())
+}
+
+/// Forget the settings of the events `names` kept besides their [EventSettings], or of
+/// every event if `names` is [None], so the settings file no longer has them once it is
+/// saved and they are not restored on the next run.
+fn clear_overrides<I: LogInstance>(world: &mut World, names: Option<&[String]>) {
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    match names {
+        Some(names) => {
+            plugin_settings
+                .previous_settings
+                .retain(|name, _| !names.contains(name));
+            plugin_settings
+                .templates
+                .retain(|name, _| !names.contains(name));
+            plugin_settings
+                .entity_filters
+                .retain(|name, _| !names.contains(name));
+            plugin_settings
+                .sounds
+                .retain(|name, _| !names.contains(name));
+            plugin_settings
+                .output_files
+                .retain(|name, _| !names.contains(name));
+        }
+        None => {
+            plugin_settings.previous_settings.clear();
+            plugin_settings.templates.clear();
+            plugin_settings.entity_filters.clear();
+            plugin_settings.sounds.clear();
+            plugin_settings.output_files.clear();
+        }
+    }
+}
+
+/// Reset the events `names` to their default settings, or every event if `names` is [None].
+/// This can be undone like the other changes made in the window, and the settings file
+/// is only updated when the settings are saved.
+fn reset_events<I: LogInstance>(
+    world: &mut World,
+    state: &mut LogEventsWindowState<I>,
+    names: Option<&[String]>,
+) {
+    world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds<I>>| {
+        for (name, registered) in log_settings_ids.iter() {
+            if names.is_none_or(|names| names.contains(name)) {
+                if let Some(settings) = get_log_settings_mut_by_id(world, &registered.id) {
+                    *settings = EventSettings::default();
+                }
+            }
+        }
+    });
+    clear_overrides::<I>(world, names);
+    state.file_status = Some(Ok(match names {
+        Some(names) => format!("Reset {}, saved on exit", names.join(", ")),
+        None => "Reset all events to their default settings, saved on exit".to_string(),
+    }));
+}
+
+fn reset_all_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    if ui
+        .button("Reset all to defaults")
+        .on_hover_text("Reset the settings of every event, the settings file is updated on exit")
+        .clicked()
+    {
+        state.confirm_reset_all = true;
+    }
+    if !state.confirm_reset_all {
+        return;
+    }
+    egui::Window::new("Reset all settings?")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0., 0.])
+        .show(ui.ctx(), |ui| {
+            ui.label("Every event will go back to its default settings.");
+            ui.horizontal(|ui| {
+                if ui.button("Reset all").clicked() {
+                    reset_events(world, state, None);
+                    state.confirm_reset_all = false;
+                }
+                if ui.button("Cancel").clicked() {
+                    state.confirm_reset_all = false;
+                }
+            });
+        });
+}
+
+/// A change made at once to the selected events.
+enum BulkEdit {
+    Enabled(bool),
+    Level(Level),
+    Format(LogFormat),
+}
+
+impl BulkEdit {
+    fn apply(&self, event_settings: &mut EventSettings) {
+        match self {
+            BulkEdit::Enabled(enabled) => event_settings.enabled = *enabled,
+            BulkEdit::Level(level) => event_settings.level = *level,
+            BulkEdit::Format(format) => event_settings.format = format.clone(),
+        }
+    }
+}
 
-pub(crate) fn settings_window_ui(
+/// The buttons editing every selected event at once, shown when events are selected.
+fn selection_ui<I: LogInstance>(
     world: &mut World,
     ui: &mut egui::Ui,
-    state: &mut LogEventsWindowState,
+    state: &mut LogEventsWindowState<I>,
+    log_settings_ids: &LogSettingsIds<I>,
 ) {
-    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings>();
+    if state.selection.is_empty() {
+        return;
+    }
+    let palette = world.resource::<LogEventsPluginSettings<I>>().level_palette;
+    let mut edit = None;
+    ui.horizontal_wrapped(|ui| {
+        ui.label(format!("{} selected:", state.selection.len()));
+        if ui.button("Enable").clicked() {
+            edit = Some(BulkEdit::Enabled(true));
+        }
+        if ui.button("Disable").clicked() {
+            edit = Some(BulkEdit::Enabled(false));
+        }
+        egui::ComboBox::from_id_salt("selection_level")
+            .selected_text("Level")
+            .show_ui(ui, |ui| {
+                for level in ALL_LEVELS {
+                    if ui
+                        .selectable_label(false, colored_text_level(level, palette))
+                        .clicked()
+                    {
+                        edit = Some(BulkEdit::Level(level));
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Set the level of the selected events");
+        // A custom format needs a name, given in the settings of each event.
+        for format in LogFormat::ALL
+            .into_iter()
+            .filter(|format| !matches!(format, LogFormat::Custom(_)))
+        {
+            if ui
+                .button(format.to_string())
+                .on_hover_text(format!("Use the {} format for the selected events", format))
+                .clicked()
+            {
+                edit = Some(BulkEdit::Format(format));
+            }
+        }
+        if ui.button("Clear selection").clicked() {
+            state.selection.clear();
+            state.selection_anchor = None;
+        }
+    });
+    let Some(edit) = edit else {
+        return;
+    };
+    for name in &state.selection {
+        let Some(registered) = log_settings_ids.get(name) else {
+            continue;
+        };
+        if let Some(event_settings) = get_log_settings_mut_by_id(world, &registered.id) {
+            edit.apply(event_settings);
+        }
+    }
+}
+
+fn settings_file_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    ui.strong("📁 Settings file");
+    ui.horizontal(|ui| {
+        ui.text_edit_singleline(&mut state.settings_path);
+        let path = PathBuf::from(&state.settings_path);
+        if ui
+            .button("Load")
+            .on_hover_text("Load the settings from this file")
+            .clicked()
+        {
+            state.file_status = Some(
+                load_settings_file::<I>(world, &path)
+                    .map(|_| format!("Loaded {:?}", path))
+                    .map_err(|err| format!("Could not load {:?}: {}", path, err)),
+            );
+        }
+        if ui
+            .button("Save As")
+            .on_hover_text("Save the current settings to this file")
+            .clicked()
+        {
+            state.file_status = Some(
+                save_settings_file::<I>(world, &path)
+                    .map(|_| format!("Saved {:?}", path))
+                    .map_err(|err| format!("Could not save {:?}: {}", path, err)),
+            );
+        }
+    });
+    let palette = world.resource::<LogEventsPluginSettings<I>>().level_palette;
+    match &state.file_status {
+        Some(Ok(message)) => {
+            ui.label(message);
+        }
+        Some(Err(message)) => {
+            ui.colored_label(level_color(Level::ERROR, palette), message);
+        }
+        None => {}
+    }
+}
+
+/// The events whose current settings differ from `saved`, with their saved settings if
+/// they are in it.
+fn file_changes(
+    saved: &LoggedEventsSettings,
+    current: &LoggedEventsSettings,
+) -> Vec<(String, Option<EventSettings>)> {
+    current
+        .events_settings
+        .iter()
+        .filter_map(|(name, settings)| {
+            let saved = saved.events_settings.get(name).cloned();
+            (saved.as_ref() != Some(settings)).then(|| (name.clone(), saved))
+        })
+        .collect()
+}
+
+/// The settings in the settings file at `path`. When it does not exist yet, these are the
+/// base settings if there are some, or else the current plugin wide settings without any
+/// event.
+fn read_settings_file<I: LogInstance>(
+    world: &World,
+    path: &Path,
+) -> Result<LoggedEventsSettings, LogEventsError> {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    if path.exists() {
+        return plugin_settings.read_file(path);
+    }
+    if let Some(base) = &plugin_settings.base_settings {
+        return Ok(base.clone());
+    }
+    Ok(LoggedEventsSettings {
+        templates: default(),
+        entity_filters: default(),
+        sounds: default(),
+        output_files: default(),
+        events_settings: default(),
+        platforms: default(),
+        ..current_settings::<I>(world)
+    })
+}
+
+/// Write the current settings of the event `name` to the settings file at `path`,
+/// leaving the other entries as they are.
+fn save_event_to_file<I: LogInstance>(
+    world: &World,
+    path: &Path,
+    name: &str,
+) -> Result<(), LogEventsError> {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut saved = read_settings_file::<I>(world, path)?;
+    let platforms = settings_io::current_platforms();
+    let mut applied = saved.apply_platforms(platforms);
+    if let Some(registered) = world.resource::<LogSettingsIds<I>>().get(name) {
+        if let Some(settings) = get_log_settings_by_id(world, &registered.id) {
+            applied
+                .events_settings
+                .insert(name.to_string(), settings.clone());
+        }
+    }
+    saved = saved.store_platforms(&applied, platforms);
+    plugin_settings.write_file(path, &saved)
+}
+
+fn file_changes_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    egui::CollapsingHeader::new("Changes from the settings file").show(ui, |ui| {
+        let path = world
+            .resource::<LogEventsPluginSettings<I>>()
+            .settings_path()
+            .to_path_buf();
+        if ui
+            .button("Refresh")
+            .on_hover_text("Read the settings file again and list the changes from it")
+            .clicked()
+            || state.file_changes.is_none()
+        {
+            match read_settings_file::<I>(world, &path) {
+                Ok(saved) => {
+                    state.file_changes = Some(file_changes(&saved, &current_settings::<I>(world)))
+                }
+                Err(err) => {
+                    ui.label(format!("Could not read {:?}: {}", path, err));
+                    return;
+                }
+            }
+        }
+        let Some(changes) = state.file_changes.clone() else {
+            return;
+        };
+        if changes.is_empty() {
+            ui.label("The events are as in the settings file");
+            return;
+        }
+        let mut reload = false;
+        for (name, saved) in changes {
+            ui.horizontal(|ui| {
+                let label = match saved {
+                    Some(_) => format!("~ {}", name),
+                    None => format!("+ {}", name),
+                };
+                ui.label(label).on_hover_text(match saved {
+                    Some(_) => "Changed since the file was written",
+                    None => "Not in the file",
+                });
+                if ui
+                    .button("Revert")
+                    .on_hover_text("Go back to the settings of the file")
+                    .clicked()
+                {
+                    if let Some(registered) = world.resource::<LogSettingsIds<I>>().get(&name) {
+                        let id = registered.id;
+                        if let Some(settings) = get_log_settings_mut_by_id(world, &id) {
+                            *settings = saved.unwrap_or_default();
+                        }
+                    }
+                    reload = true;
+                }
+                if ui
+                    .button("Save")
+                    .on_hover_text("Write only the settings of this event to the file")
+                    .clicked()
+                {
+                    state.file_status = Some(
+                        save_event_to_file::<I>(world, &path, &name)
+                            .map(|_| format!("Saved {} to {:?}", name, path))
+                            .map_err(|err| format!("Could not save {:?}: {}", path, err)),
+                    );
+                    reload = true;
+                }
+            });
+        }
+        if reload {
+            state.file_changes = None;
+        }
+    });
+}
+
+fn template_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui, name: &str) {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut template = plugin_settings
+        .templates
+        .get(name)
+        .cloned()
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Template").on_hover_text(
+            "Placeholders: {name}, {level}, {entity} and {payload}. \
+            Leave empty to use the default format",
+        );
+        if ui
+            .add(egui::TextEdit::singleline(&mut template).hint_text("{name}: {payload}"))
+            .changed()
+        {
+            let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+            if template.is_empty() {
+                plugin_settings.templates.remove(name);
+            } else {
+                plugin_settings.templates.insert(name.to_string(), template);
+            }
+        }
+    });
+}
+
+fn entity_filter_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui, name: &str) {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut filter = plugin_settings
+        .entity_filters
+        .get(name)
+        .cloned()
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Entity filter").on_hover_text(
+            "Only log the entities whose Name matches this regular expression. \
+            Leave empty to log every entity",
+        );
+        let response = ui.add(egui::TextEdit::singleline(&mut filter).hint_text("^Player"));
+        if !filter.is_empty() && regex::Regex::new(&filter).is_err() {
+            ui.colored_label(ui.visuals().error_fg_color, "Invalid")
+                .on_hover_text("This expression is ignored");
+        }
+        if response.changed() {
+            let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+            if filter.is_empty() {
+                plugin_settings.entity_filters.remove(name);
+            } else {
+                plugin_settings
+                    .entity_filters
+                    .insert(name.to_string(), filter);
+            }
+        }
+    });
+}
+
+/// The file where the logs of the event `name` are written, shown when they are.
+fn output_file_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui, name: &str) {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut path = plugin_settings
+        .output_files
+        .get(name)
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Output file").on_hover_text(
+            "The file where the logs are written. Leave empty to use the default file",
+        );
+        let hint = default_output_path(name).display().to_string();
+        if ui
+            .add(egui::TextEdit::singleline(&mut path).hint_text(hint))
+            .changed()
+        {
+            let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+            if path.is_empty() {
+                plugin_settings.output_files.remove(name);
+            } else {
+                plugin_settings
+                    .output_files
+                    .insert(name.to_string(), PathBuf::from(path));
+            }
+        }
+    });
+}
+
+#[cfg(feature = "audio")]
+fn sound_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui, name: &str) {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut sound = plugin_settings
+        .sounds
+        .get(name)
+        .cloned()
+        .unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("Sound").on_hover_text(
+            "The asset path of a sound played when this event is logged. \
+            Leave empty to play no sound",
+        );
+        let mut changed = ui
+            .add(egui::TextEdit::singleline(&mut sound.path).hint_text("sounds/blip.ogg"))
+            .changed();
+        if !sound.path.is_empty() {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut sound.volume)
+                        .range(0.0..=f32::MAX)
+                        .speed(0.01)
+                        .prefix("volume "),
+                )
+                .changed();
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut sound.cooldown)
+                        .range(0.0..=f32::MAX)
+                        .speed(0.1)
+                        .prefix("cooldown ")
+                        .suffix("s"),
+                )
+                .on_hover_text("The minimum time between two plays of the sound")
+                .changed();
+        }
+        if changed {
+            let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+            if sound.path.is_empty() {
+                plugin_settings.sounds.remove(name);
+            } else {
+                plugin_settings.sounds.insert(name.to_string(), sound);
+            }
+        }
+    });
+}
+
+/// The last log of an event, as copied for bug reports.
+#[derive(Serialize)]
+struct CopiedLog<'a> {
+    event: &'a str,
+    level: &'a str,
+    entity: Option<&'a str>,
+    payload: &'a str,
+    line: &'a str,
+}
+
+/// The buttons of the context menu of an event copying its last log to the clipboard.
+fn copy_last_log_ui(ui: &mut egui::Ui, name: &str, level: Level, registered: &RegisteredEvent) {
+    let activity = registered.stats.lock();
+    let (Some(line), Some(payload)) = (&activity.last_line, &activity.last_payload) else {
+        ui.add_enabled(false, egui::Button::new("Copy last log line"))
+            .on_disabled_hover_text("Not logged during this run");
+        return;
+    };
+    let copied = CopiedLog {
+        event: name,
+        level: level.as_str(),
+        entity: activity.last_entity.as_deref(),
+        payload,
+        line,
+    };
+    let text = if ui.button("Copy last log line").clicked() {
+        Some(line.clone())
+    } else if ui.button("Copy as RON").clicked() {
+        ron::ser::to_string_pretty(&copied, ron::ser::PrettyConfig::default()).ok()
+    } else if ui.button("Copy as JSON").clicked() {
+        serde_json::to_string_pretty(&copied).ok()
+    } else {
+        None
+    };
+    if let Some(text) = text {
+        ui.ctx().copy_text(text);
+        ui.close_menu();
+    }
+}
+
+/// An expandable preview of the payload of the last log of `registered`.
+fn last_payload_ui(ui: &mut egui::Ui, registered: &RegisteredEvent) {
+    let Some(payload) = registered.stats.lock().last_payload.clone() else {
+        return;
+    };
+    egui::CollapsingHeader::new("Last payload")
+        .id_salt(("last_payload", registered.id.index()))
+        .show(ui, |ui| {
+            ui.add(
+                // A &str can be selected and copied but not edited.
+                egui::TextEdit::multiline(&mut payload.as_str())
+                    .code_editor()
+                    .desired_width(f32::INFINITY),
+            );
+        });
+}
+
+/// When an event was first and last logged during the run.
+fn occurrences_text(activity: &EventActivity) -> String {
+    match (activity.first_logged, activity.last_logged) {
+        (Some(first), Some(last)) => format!(
+            "First logged {:.1} s ago\nLast logged {:.1} s ago",
+            first.elapsed().as_secs_f32(),
+            last.elapsed().as_secs_f32()
+        ),
+        _ => "Not logged during this run".to_string(),
+    }
+}
+
+/// The text shown when hovering a field of [EventSettings], which is the first paragraph
+/// of its doc comment without the markdown of the links and code spans.
+fn field_hover_text(field: &NamedField) -> String {
+    let paragraph = field
+        .docs()
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut text = String::with_capacity(paragraph.len());
+    let mut chars = paragraph.chars();
+    let mut after_link = false;
+    while let Some(char) = chars.next() {
+        match char {
+            '(' if after_link => {
+                chars.by_ref().find(|&char| char == ')');
+            }
+            '[' | ']' | '`' => {}
+            _ => text.push(char),
+        }
+        after_link = char == ']';
+    }
+    text
+}
+
+/// `"log_unavailable"` becomes `"Log Unavailable"`.
+fn field_label(field: &str) -> String {
+    field
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Show a widget for each field of `settings` using reflection, so new fields appear
+/// without writing their UI. The [Level] is not reflected and shown apart.
+fn event_settings_ui(
+    ui: &mut egui::Ui,
+    settings: &mut EventSettings,
+    registered: &RegisteredEvent,
+) {
+    let TypeInfo::Struct(info) = EventSettings::type_info() else {
+        return;
+    };
+    for named_field in info.iter() {
+        let field = named_field.name();
+        if field == "notify" && !cfg!(feature = "notifications") {
+            continue;
+        }
+        if field == "gizmo" && !cfg!(feature = "gizmos") {
+            continue;
+        }
+        let Some(value) = settings.field_mut(field) else {
+            continue;
+        };
+        let label = field_label(field);
+        let hover_text = field_hover_text(named_field);
+        let hover_text = hover_text.as_str();
+        if let Some(value) = value.try_downcast_mut::<bool>() {
+            ui.checkbox(value, label).on_hover_text(hover_text);
+        } else if let Some(value) = value.try_downcast_mut::<Option<f32>>() {
+            optional_value_ui(
+                ui,
+                value,
+                label,
+                hover_text,
+                DEFAULT_PERIOD,
+                |ui, period| {
+                    ui.add(
+                        egui::DragValue::new(period)
+                            .range(0.1..=3600.)
+                            .speed(0.1)
+                            .suffix(" s"),
+                    );
+                },
+            );
+        } else if let Some(value) = value.try_downcast_mut::<Option<u32>>() {
+            optional_value_ui(ui, value, label, hover_text, DEFAULT_COUNT, |ui, count| {
+                ui.add(egui::DragValue::new(count).range(1..=u32::MAX));
+                if field == "max_logs" {
+                    let activity = registered.stats.lock();
+                    ui.label(format!(
+                        "logged {}, suppressed {}",
+                        activity.logged, activity.suppressed
+                    ));
+                }
+            });
+        } else if let ReflectMut::Enum(value) = value.reflect_mut() {
+            enum_ui(ui, value, (field, registered.id.index()), label, hover_text);
+        } else {
+            ui.label(format!("{}: {:?}", label, value))
+                .on_hover_text(hover_text);
+        }
+    }
+}
+
+/// A checkbox enabling an optional setting, followed by the widget of its value.
+fn optional_value_ui<T>(
+    ui: &mut egui::Ui,
+    value: &mut Option<T>,
+    label: String,
+    hover_text: &str,
+    default: T,
+    value_ui: impl FnOnce(&mut egui::Ui, &mut T),
+) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        if ui
+            .checkbox(&mut enabled, label)
+            .on_hover_text(hover_text)
+            .changed()
+        {
+            *value = enabled.then_some(default);
+        }
+        if let Some(value) = value {
+            value_ui(ui, value);
+        }
+    });
+}
+
+/// A combo box to pick one of the variants of a reflected enum, followed by a text field
+/// for the variants holding a [String], like [LogFormat::Custom].
+fn enum_ui(
+    ui: &mut egui::Ui,
+    value: &mut dyn Enum,
+    id_salt: impl std::hash::Hash,
+    label: String,
+    hover_text: &str,
+) {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.label(label).on_hover_text(hover_text);
+        let mut selected = value.variant_name();
+        egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for variant in info.iter().filter(|variant| editable_variant(variant)) {
+                    ui.selectable_value(&mut selected, variant.name(), variant.name());
+                }
+            });
+        if selected != value.variant_name() {
+            let variant = match info.variant(selected) {
+                Some(VariantInfo::Tuple(_)) => {
+                    let mut tuple = DynamicTuple::default();
+                    tuple.insert(String::new());
+                    DynamicVariant::Tuple(tuple)
+                }
+                _ => DynamicVariant::Unit,
+            };
+            value.apply(&DynamicEnum::new(selected, variant));
+        }
+        if let Some(text) = value
+            .field_at_mut(0)
+            .and_then(|field| field.try_downcast_mut::<String>())
+        {
+            ui.text_edit_singleline(text);
+        }
+    });
+}
+
+/// Whether [enum_ui] can pick `variant`: a unit variant or one holding a single [String].
+fn editable_variant(variant: &VariantInfo) -> bool {
+    match variant {
+        VariantInfo::Unit(_) => true,
+        VariantInfo::Tuple(tuple) => {
+            tuple.field_len() == 1 && tuple.field_at(0).is_some_and(|field| field.is::<String>())
+        }
+        VariantInfo::Struct(_) => false,
+    }
+}
+
+/// Warn that nothing is logged while the plugin is disabled.
+fn disabled_banner_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui) {
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    if plugin_settings.enabled {
+        return;
+    }
+    egui::Frame::group(ui.style())
+        .fill(ui.visuals().warn_fg_color.gamma_multiply(0.15))
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    "⚠ Global logging disabled, events will not be logged",
+                );
+                if ui.button("Enable").clicked() {
+                    plugin_settings.enabled = true;
+                }
+            });
+        });
+    ui.separator();
+}
+
+fn channels_ui<I: LogInstance>(world: &mut World, ui: &mut egui::Ui) {
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    if plugin_settings.channels.is_empty() {
+        return;
+    }
+    ui.strong("Channels");
+    let palette = plugin_settings.level_palette;
+    for (name, channel) in plugin_settings.channels.iter_mut() {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut channel.enabled, name);
+            egui::ComboBox::from_id_salt(("channel", name))
+                .selected_text(colored_text_level(channel.level, palette))
+                .show_ui(ui, |ui| {
+                    for level in ALL_LEVELS {
+                        ui.selectable_value(
+                            &mut channel.level,
+                            level,
+                            colored_text_level(level, palette),
+                        );
+                    }
+                });
+        });
+    }
+
+    ui.separator();
+}
+
+fn component_history_ui(world: &World, ui: &mut egui::Ui) {
+    let history = world.resource::<ComponentHistory>();
+    if history.is_empty() {
+        return;
+    }
+    egui::CollapsingHeader::new("Component history").show(ui, |ui| {
+        for (component, entities) in history.iter() {
+            egui::CollapsingHeader::new(short_type_name(component))
+                .id_salt(("component_history", component))
+                .show(ui, |ui| {
+                    for (entity, values) in entities {
+                        egui::CollapsingHeader::new(format!("{} ({})", entity, values.len()))
+                            .id_salt(("component_history", component, entity))
+                            .show(ui, |ui| {
+                                for value in values.iter().rev() {
+                                    ui.monospace(value);
+                                }
+                            });
+                    }
+                });
+        }
+    });
+}
+
+fn legend_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    egui::CollapsingHeader::new("Level colors").show(ui, |ui| {
+        let mut colorblind = plugin_settings.level_palette == LevelPalette::Colorblind;
+        if ui
+            .checkbox(&mut colorblind, "Colorblind-safe palette")
+            .changed()
+        {
+            plugin_settings.level_palette = if colorblind {
+                LevelPalette::Colorblind
+            } else {
+                LevelPalette::Default
+            };
+        }
+        for level in ALL_LEVELS {
+            ui.horizontal(|ui| {
+                let filter = LevelFilter::Level(level);
+                if ui
+                    .selectable_label(
+                        state.level_filter == filter,
+                        colored_text_level(level, plugin_settings.level_palette),
+                    )
+                    .on_hover_text("Only show the events logged at this level")
+                    .clicked()
+                {
+                    state.level_filter = if state.level_filter == filter {
+                        LevelFilter::All
+                    } else {
+                        filter
+                    };
+                }
+                ui.label(level_description(level));
+            });
+        }
+    });
+
+    ui.separator();
+}
+
+fn presets_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    ui.horizontal(|ui| {
+        ui.label("Presets")
+            .on_hover_text("Save the current search filters under a name to recall them later");
+        egui::ComboBox::from_id_salt("filter_presets")
+            .selected_text(state.preset_name.as_str())
+            .show_ui(ui, |ui| {
+                for (name, preset) in &plugin_settings.filter_presets {
+                    if ui
+                        .selectable_label(state.preset_name == *name, name)
+                        .clicked()
+                    {
+                        state.preset_name = name.clone();
+                        state.apply_preset(preset);
+                    }
+                }
+            });
+        ui.add(egui::TextEdit::singleline(&mut state.preset_name).desired_width(100.));
+        if ui
+            .add_enabled(!state.preset_name.is_empty(), egui::Button::new("Save"))
+            .clicked()
+        {
+            plugin_settings
+                .filter_presets
+                .insert(state.preset_name.clone(), state.preset());
+        }
+        let exists = plugin_settings
+            .filter_presets
+            .contains_key(&state.preset_name);
+        if ui
+            .add_enabled(exists, egui::Button::new("🗑"))
+            .on_hover_text("Delete this preset")
+            .clicked()
+        {
+            plugin_settings.filter_presets.remove(&state.preset_name);
+            state.preset_name.clear();
+        }
+    });
+}
+
+fn about_ui<I: LogInstance>(world: &World, ui: &mut egui::Ui, state: &LogEventsWindowState<I>) {
+    egui::CollapsingHeader::new("About").show(ui, |ui| {
+        ui.label(format!("bevy_log_events {}", env!("CARGO_PKG_VERSION")));
+        let settings_path = world
+            .resource::<LogEventsPluginSettings<I>>()
+            .settings_path();
+        ui.label(format!("Settings file: {}", settings_path.display()))
+            .on_hover_text("Where the settings are saved on exit");
+        let usage = MemoryUsage::measure(
+            world.resource::<LogSettingsIds<I>>(),
+            world.resource::<LogEventsPluginSettings<I>>(),
+            state.history(),
+        );
+        ui.label(format!("Settings resources: {}", usage.settings_resources));
+        ui.label(format!("History entries: {}", usage.history_entries));
+        ui.label(format!("Map entries: {}", usage.map_entries));
+        ui.label(format!("Pending digests: {}", usage.pending_digests));
+        ui.label(format!(
+            "Estimated memory: {:.1} KiB",
+            usage.estimated_bytes as f32 / 1024.
+        ))
+        .on_hover_text("A rough estimation, not counting the allocator overhead");
+    });
+
+    ui.separator();
+}
+
+/// Draw the undo/redo controls and apply them. Returns true if the settings were
+/// replaced by an undo or a redo.
+fn history_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) -> bool {
+    let mut undo = false;
+    let mut redo = false;
+    if ui.memory(|memory| memory.focused().is_none()) {
+        ui.input_mut(|input| {
+            redo = input.consume_key(
+                egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+                egui::Key::Z,
+            ) || input.consume_key(egui::Modifiers::COMMAND, egui::Key::Y);
+            undo = input.consume_key(egui::Modifiers::COMMAND, egui::Key::Z);
+        });
+    }
+    ui.horizontal(|ui| {
+        undo |= ui
+            .add_enabled(!state.undo_stack.is_empty(), egui::Button::new("⟲ Undo"))
+            .on_hover_text("Ctrl+Z")
+            .clicked();
+        redo |= ui
+            .add_enabled(!state.redo_stack.is_empty(), egui::Button::new("⟳ Redo"))
+            .on_hover_text("Ctrl+Y")
+            .clicked();
+        if ui
+            .button("Revert to loaded file")
+            .on_hover_text("Discard the changes made since the settings file was loaded")
+            .clicked()
+        {
+            let path = world
+                .resource::<LogEventsPluginSettings<I>>()
+                .saved_settings
+                .clone();
+            state.file_status = Some(
+                load_settings_file::<I>(world, &path)
+                    .map(|_| format!("Reverted to {:?}", path))
+                    .map_err(|err| format!("Could not load {:?}: {}", path, err)),
+            );
+        }
+    });
+    if undo {
+        if let Some(previous) = state.undo_stack.pop() {
+            state.redo_stack.push(current_settings::<I>(world));
+            apply_settings::<I>(world, previous);
+            return true;
+        }
+    } else if redo {
+        if let Some(next) = state.redo_stack.pop() {
+            state.undo_stack.push(current_settings::<I>(world));
+            apply_settings::<I>(world, next);
+            return true;
+        }
+    }
+    false
+}
+
+/// Make the widgets of `ui` larger and further apart in [UiDensity::Comfortable].
+fn apply_density(ui: &mut egui::Ui, density: UiDensity) {
+    if density == UiDensity::Comfortable {
+        let spacing = ui.spacing_mut();
+        spacing.item_spacing = egui::vec2(10., 8.);
+        spacing.button_padding = egui::vec2(8., 6.);
+        spacing.interact_size.y = 32.;
+        spacing.icon_width = 20.;
+        spacing.icon_spacing = 8.;
+    }
+}
+
+/// The keys used to move between the events and toggle them, unless a widget already
+/// uses the keyboard.
+struct RowKeys {
+    up: bool,
+    down: bool,
+    toggle: bool,
+}
+
+impl RowKeys {
+    fn read(ui: &egui::Ui) -> Self {
+        if ui.memory(|memory| memory.focused().is_some()) {
+            return Self {
+                up: false,
+                down: false,
+                toggle: false,
+            };
+        }
+        ui.input_mut(|input| Self {
+            up: input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            down: input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            toggle: input.consume_key(egui::Modifiers::NONE, egui::Key::Space),
+        })
+    }
+
+    /// The row selected after these keys, among `rows` rows.
+    fn select(&self, selected: Option<usize>, rows: usize) -> Option<usize> {
+        let last = rows.checked_sub(1)?;
+        match selected {
+            _ if !self.up && !self.down => selected.map(|row| row.min(last)),
+            None => Some(if self.up { last } else { 0 }),
+            Some(row) if self.up => Some(row.saturating_sub(1)),
+            Some(row) => Some((row + 1).min(last)),
+        }
+    }
+}
+
+pub(crate) fn settings_window_ui<I: LogInstance>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    state: &mut LogEventsWindowState<I>,
+) {
+    apply_density(
+        ui,
+        world.resource::<LogEventsPluginSettings<I>>().ui_density,
+    );
+    let before = may_edit(ui.ctx()).then(|| current_settings::<I>(world));
+    disabled_banner_ui::<I>(world, ui);
+    let restored = history_ui(world, ui, state);
+
+    settings_file_ui(world, ui, state);
+    file_changes_ui(world, ui, state);
+
+    ui.separator();
+
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    let palette = plugin_settings.level_palette;
     ui.strong("Plugin settings");
     ui.checkbox(&mut plugin_settings.enabled, "Enabled");
+    ui.checkbox(&mut plugin_settings.show_overlay, "Show Overlay")
+        .on_hover_text("Show the quick toggles of the pinned events");
+    ui.checkbox(&mut plugin_settings.show_log_viewer, "Show Log Viewer")
+        .on_hover_text("Show the last logs in another window");
+    ui.checkbox(&mut plugin_settings.show_type_info, "Show Type Info")
+        .on_hover_text("Append the size and the type id hash of the logged type to each log");
+    let mut comfortable = plugin_settings.ui_density == UiDensity::Comfortable;
+    if ui
+        .checkbox(&mut comfortable, "Larger Widgets")
+        .on_hover_text("Space the widgets of this window out, for touch screens")
+        .changed()
+    {
+        plugin_settings.ui_density = if comfortable {
+            UiDensity::Comfortable
+        } else {
+            UiDensity::Compact
+        };
+    }
+    ui.horizontal(|ui| {
+        ui.label("Names")
+            .on_hover_text("How the names of the events are shown in the logs and here");
+        egui::ComboBox::from_id_salt("name_style")
+            .selected_text(plugin_settings.name_style.to_string())
+            .show_ui(ui, |ui| {
+                for style in NameStyle::ALL {
+                    ui.selectable_value(&mut plugin_settings.name_style, style, style.to_string());
+                }
+            });
+    });
+    ui.horizontal(|ui| {
+        ui.label("Minimum level")
+            .on_hover_text("Events logged at a more verbose level will be ignored");
+        egui::ComboBox::from_id_salt("min_level")
+            .selected_text(colored_text_level(plugin_settings.min_level, palette))
+            .show_ui(ui, |ui| {
+                for level in ALL_LEVELS {
+                    ui.selectable_value(
+                        &mut plugin_settings.min_level,
+                        level,
+                        colored_text_level(level, palette),
+                    );
+                }
+            });
+    });
 
     ui.separator();
 
+    channels_ui::<I>(world, ui);
+    component_history_ui(world, ui);
+    legend_ui(world, ui, state);
+    about_ui(world, ui, state);
+
     ui.strong("🔍 Search");
+    let previous_preset = state.preset();
     ui.horizontal(|ui| {
         ui.label("Name");
         ui.text_edit_singleline(&mut state.name_filter);
         selectable_label_switch!(state.case_sensitive, ui, "Aa", "Match Case");
         selectable_label_switch!(state.use_regex, ui, ".*", "Use Regular Expression");
-        state.update_regex();
+        selectable_label_switch!(
+            state.use_expression,
+            ui,
+            "ƒ",
+            "Use Filter Expression, like name~\"combat\" && level>=DEBUG && enabled"
+        );
     });
     ui.horizontal(|ui| {
         ui.label("Enabled");
@@ -185,25 +1540,73 @@ pub(crate) fn settings_window_ui(
     ui.horizontal(|ui| {
         ui.label("Level");
         egui::ComboBox::from_id_salt("level_filter")
-            .selected_text(state.level_filter.to_label())
+            .selected_text(state.level_filter.to_label(palette))
             .show_ui(ui, |ui| {
                 ui.selectable_value(
                     &mut state.level_filter,
                     LevelFilter::All,
-                    LevelFilter::All.to_label(),
+                    LevelFilter::All.to_label(palette),
                 );
                 for level in ALL_LEVELS {
                     let level = LevelFilter::Level(level);
-                    ui.selectable_value(&mut state.level_filter, level, level.to_label());
+                    ui.selectable_value(&mut state.level_filter, level, level.to_label(palette));
+                }
+            });
+    });
+    if state.preset() != previous_preset {
+        state.update_filter();
+    }
+    if let Some(error) = state.filter.error() {
+        ui.colored_label(level_color(Level::ERROR, palette), error);
+    }
+    ui.horizontal(|ui| {
+        ui.label("Sort by");
+        egui::ComboBox::from_id_salt("sort_mode")
+            .selected_text(state.sort_mode.to_string())
+            .show_ui(ui, |ui| {
+                for mode in SortMode::iter() {
+                    ui.selectable_value(&mut state.sort_mode, mode, mode.to_string());
                 }
             });
     });
-    world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds>| {
+    presets_ui(world, ui, state);
+    reset_all_ui(world, ui, state);
+    let mut to_reset = Vec::new();
+    let mut to_pin = Vec::new();
+    let mut to_star = Vec::new();
+    let mut to_solo = None;
+    let soloed = soloed(world.resource::<LogEventsPluginSettings<I>>()).map(str::to_string);
+    let pinned = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .pinned
+        .clone();
+    let name_style = world.resource::<LogEventsPluginSettings<I>>().name_style;
+    let disabled_groups = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .disabled_groups
+        .clone();
+    let favorites = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .favorites
+        .clone();
+    let row_widgets = std::mem::take(
+        &mut world
+            .resource_mut::<LogEventsPluginSettings<I>>()
+            .row_widgets,
+    );
+    let keys = RowKeys::read(ui);
+    state.selected_row = keys.select(state.selected_row, state.shown);
+    world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds<I>>| {
         ui.label(format!(
             "Displayed : {}/{}",
             state.shown,
             log_settings_ids.len()
-        ));
+        ))
+        .on_hover_text(
+            "Select an event with the arrow keys and toggle it with space. Click the names of \
+            the events with Shift or Ctrl held to select several and edit them at once",
+        );
+        selection_ui(world, ui, state, &log_settings_ids);
 
         ui.separator();
 
@@ -211,49 +1614,443 @@ pub(crate) fn settings_window_ui(
             .auto_shrink(true)
             .show(ui, |ui| {
                 let mut shown = 0;
-                for (name, id) in log_settings_ids.iter() {
-                    if !state.name_contains_filter(name) {
+                let mut shown_names = Vec::new();
+                let mut clicked = None;
+                let mut entries: Vec<_> = log_settings_ids.iter().collect();
+                state.sort_mode.sort(world, &mut entries);
+                entries.sort_by_key(|(name, _)| !favorites.contains(*name));
+                for (name, registered) in entries {
+                    let is_favorite = favorites.contains(name);
+                    let id = &registered.id;
+                    let Some(settings) = get_log_settings_by_id(world, id) else {
                         continue;
-                    }
-                    let event_settings = get_log_settings_mut_by_id(world, id);
-                    if !state.must_show(event_settings) {
+                    };
+                    let logged = world
+                        .resource::<LogEventsPluginSettings<I>>()
+                        .must_log_event(settings, registered.channel.as_deref(), registered.group);
+                    let Some(event_settings) = get_log_settings_mut_by_id(world, id) else {
+                        continue;
+                    };
+                    if !is_favorite
+                        && !state.filter.matches(
+                            name,
+                            event_settings,
+                            registered.channel.as_deref(),
+                            registered.group,
+                        )
+                    {
                         continue;
                     }
                     if shown != 0 {
                         ui.separator();
                     }
+                    let selected = state.selected_row == Some(shown);
+                    let in_selection = state.selection.contains(name);
                     shown += 1;
-                    ui.strong(name);
-                    ui.checkbox(&mut event_settings.enabled, "Enabled");
-                    ui.checkbox(&mut event_settings.pretty, "Pretty Debug");
+                    shown_names.push(name.clone());
+                    if selected && keys.toggle {
+                        event_settings.enabled = !event_settings.enabled;
+                    }
+                    // The events that will not be logged are grayed out but can still be edited.
+                    let text_color = ui.visuals().override_text_color;
+                    if !logged {
+                        ui.visuals_mut().override_text_color = Some(ui.visuals().weak_text_color());
+                    }
+                    let header = ui.horizontal(|ui| {
+                        let star = if is_favorite { "★" } else { "☆" };
+                        if ui
+                            .selectable_label(is_favorite, star)
+                            .on_hover_text("Always show first")
+                            .clicked()
+                        {
+                            to_star.push(name.clone());
+                        }
+                        let is_pinned = pinned.contains(name);
+                        let can_pin = is_pinned || pinned.len() < MAX_PINNED;
+                        if ui
+                            .add_enabled(can_pin, egui::SelectableLabel::new(is_pinned, "📌"))
+                            .on_hover_text("Pin to the overlay")
+                            .clicked()
+                        {
+                            to_pin.push(name.clone());
+                        }
+                        let is_soloed = soloed.as_ref() == Some(name);
+                        if ui
+                            .selectable_label(is_soloed, "S")
+                            .on_hover_text(if is_soloed {
+                                "Restore the other events as they were"
+                            } else {
+                                "Solo: disable every other event until clicked again"
+                            })
+                            .clicked()
+                        {
+                            to_solo = Some(name.clone());
+                        }
+                        let name_label = ui
+                            .selectable_label(
+                                in_selection,
+                                egui::RichText::new(name_style.format_name(name)).strong(),
+                            )
+                            .on_hover_text(format!(
+                                "{}\n{}",
+                                name,
+                                occurrences_text(&registered.stats.lock())
+                            ));
+                        if name_label.clicked() {
+                            clicked = Some((name.clone(), ui.input(|input| input.modifiers)));
+                        }
+                        name_label.context_menu(|ui| {
+                            if ui.button("Reset counters").clicked() {
+                                let mut activity = registered.stats.lock();
+                                activity.logged = 0;
+                                activity.suppressed = 0;
+                                ui.close_menu();
+                            }
+                            copy_last_log_ui(ui, name, event_settings.level, registered);
+                        });
+                        if let Some(channel) = &registered.channel {
+                            ui.label(format!("[{}]", channel));
+                        }
+                        if let Some(group) = registered.group {
+                            let label = ui.weak(format!("({})", group));
+                            if disabled_groups.contains(group) {
+                                label.on_hover_text("This group is disabled");
+                            }
+                        }
+                        if ui
+                            .small_button("⟲")
+                            .on_hover_text("Reset to default")
+                            .clicked()
+                        {
+                            to_reset.push(name.clone());
+                        }
+                    });
+                    if selected {
+                        let stroke = ui.visuals().selection.stroke;
+                        ui.painter()
+                            .rect_stroke(header.response.rect.expand(2.), 2., stroke);
+                        if keys.up || keys.down {
+                            header.response.scroll_to_me(None);
+                        }
+                    }
+                    event_settings_ui(ui, event_settings, registered);
                     egui::ComboBox::from_id_salt(id.index())
-                        .selected_text(colored_text_level(event_settings.level))
+                        .selected_text(colored_text_level(event_settings.level, palette))
                         .show_ui(ui, |ui| {
                             for level in ALL_LEVELS {
                                 ui.selectable_value(
                                     &mut event_settings.level,
                                     level,
-                                    colored_text_level(level),
+                                    colored_text_level(level, palette),
                                 );
                             }
                         });
+                    template_ui::<I>(world, ui, name);
+                    entity_filter_ui::<I>(world, ui, name);
+                    if get_log_settings_by_id(world, id)
+                        .is_some_and(|settings| settings.output != LogOutput::Tracing)
+                    {
+                        output_file_ui::<I>(world, ui, name);
+                    }
+                    #[cfg(feature = "audio")]
+                    sound_ui::<I>(world, ui, name);
+                    last_payload_ui(ui, registered);
+                    if let Some(event_settings) = get_log_settings_mut_by_id(world, id) {
+                        for widget in &row_widgets {
+                            widget(ui, name, event_settings);
+                        }
+                    }
+                    ui.visuals_mut().override_text_color = text_color;
                 }
                 state.shown = shown;
+                if let Some((name, modifiers)) = clicked {
+                    state.click_row(&name, modifiers, &shown_names);
+                }
             });
     });
+    if !to_reset.is_empty() {
+        reset_events(world, state, Some(&to_reset));
+    }
+    if let Some(name) = to_solo {
+        toggle_solo::<I>(world, &name);
+    }
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    plugin_settings.row_widgets = row_widgets;
+    for name in to_pin {
+        if !plugin_settings.pinned.remove(&name) {
+            plugin_settings.pinned.insert(name);
+        }
+    }
+    for name in to_star {
+        if !plugin_settings.favorites.remove(&name) {
+            plugin_settings.favorites.insert(name);
+        }
+    }
+
+    if let Some(before) = before.filter(|_| !restored) {
+        if current_settings::<I>(world) != before {
+            state.record(before);
+        }
+    }
+}
+
+/// Whether the input of this frame can change the settings from the window. The settings
+/// are only compared before and after drawing it to record the changes when it can.
+fn may_edit(ctx: &egui::Context) -> bool {
+    ctx.input(|input| {
+        input.pointer.any_down()
+            || input.pointer.any_released()
+            || input.events.iter().any(|event| {
+                matches!(
+                    event,
+                    egui::Event::Key { .. }
+                        | egui::Event::Text(_)
+                        | egui::Event::Paste(_)
+                        | egui::Event::Ime(_)
+                )
+            })
+    })
+}
+
+pub(crate) fn find_egui_context<I: LogInstance>(world: &mut World) -> Option<EguiContext> {
+    let target = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .window_context;
+    if let Some(egui_context) = target.and_then(|entity| world.get::<EguiContext>(entity)) {
+        return Some(egui_context.clone());
+    }
+    if let Ok(egui_context) = world
+        .query_filtered::<&EguiContext, With<PrimaryWindow>>()
+        .get_single(world)
+    {
+        return Some(egui_context.clone());
+    }
+    world.query::<&EguiContext>().iter(world).next().cloned()
+}
+
+/// The keys toggling the pinned events from the overlay, with Alt held.
+const OVERLAY_KEYS: [(egui::Key, &str); MAX_PINNED] = [
+    (egui::Key::Num1, "1"),
+    (egui::Key::Num2, "2"),
+    (egui::Key::Num3, "3"),
+    (egui::Key::Num4, "4"),
+    (egui::Key::Num5, "5"),
+    (egui::Key::Num6, "6"),
+    (egui::Key::Num7, "7"),
+    (egui::Key::Num8, "8"),
+    (egui::Key::Num9, "9"),
+    (egui::Key::Num0, "0"),
+];
+
+fn show_overlay<I: LogInstance>(world: &mut World) {
+    let pinned = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .pinned
+        .clone();
+    let Some(mut egui_context) = find_egui_context::<I>(world) else {
+        return;
+    };
+    let ctx = egui_context.get_mut();
+    egui::Area::new(egui::Id::new(("log_events_overlay", TypeId::of::<I>())))
+        .anchor(egui::Align2::RIGHT_TOP, [-8., 8.])
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds<I>>| {
+                    let can_use_keys = ui.memory(|memory| memory.focused().is_none());
+                    for (name, (key, key_name)) in pinned.iter().zip(OVERLAY_KEYS) {
+                        let Some(registered) = log_settings_ids.get(name) else {
+                            continue;
+                        };
+                        let Some(event_settings) =
+                            get_log_settings_mut_by_id(world, &registered.id)
+                        else {
+                            continue;
+                        };
+                        let pressed = can_use_keys
+                            && ui.input_mut(|input| input.consume_key(egui::Modifiers::ALT, key));
+                        let label = format!("Alt+{} {}", key_name, short_type_name(name));
+                        if ui
+                            .selectable_label(event_settings.enabled, label)
+                            .on_hover_text(name)
+                            .clicked()
+                            || pressed
+                        {
+                            event_settings.enabled = !event_settings.enabled;
+                        }
+                    }
+                });
+            });
+        });
 }
 
-fn show_settings_window(world: &mut World) {
-    let mut open = world.resource::<LogEventsPluginSettings>().show_window;
-    if let Ok(egui_context) = world.query::<&mut EguiContext>().get_single(world) {
-        let mut egui_context = egui_context.clone();
-        world.resource_scope(|world, mut state: Mut<LogEventsWindowState>| {
-            egui::Window::new(WINDOW_NAME)
-                .open(&mut open)
-                .show(egui_context.get_mut(), |ui| {
-                    settings_window_ui(world, ui, &mut state);
-                })
+fn show_settings_window<I: LogInstance>(world: &mut World) {
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let mut open = plugin_settings.show_window;
+    let mut layout = plugin_settings.window_layout;
+    if let Some(mut egui_context) = find_egui_context::<I>(world) {
+        let mut window = egui::Window::new(instance_title::<I>(WINDOW_NAME)).open(&mut open);
+        if let Some((x, y)) = layout.position {
+            window = window.default_pos(egui::pos2(x, y));
+        }
+        if let Some((width, height)) = layout.size {
+            window = window.default_size(egui::vec2(width, height));
+        }
+        let response = world.resource_scope(|world, mut state: Mut<LogEventsWindowState<I>>| {
+            window.show(egui_context.get_mut(), |ui| {
+                let size = ui.max_rect().size();
+                layout.size = Some((size.x, size.y));
+                settings_window_ui(world, ui, &mut state);
+            })
         });
-        world.resource_mut::<LogEventsPluginSettings>().show_window = open;
+        if let Some(response) = response {
+            let position = response.response.rect.min;
+            layout.position = Some((position.x, position.y));
+        }
+        let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+        plugin_settings.show_window = open;
+        plugin_settings.window_layout = layout;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::type_name;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{LogEvent, LogEventsPlugin, LoggedEventSettings};
+
+    #[derive(Event, Debug)]
+    struct Saved;
+
+    #[derive(Event, Debug)]
+    struct NotSaved;
+
+    /// Whether the systems of the settings window and of the overlay run.
+    fn shown(world: &mut World) -> (bool, bool) {
+        (
+            world.run_system_once(window_shown::<()>).unwrap(),
+            world.run_system_once(overlay_shown::<()>).unwrap(),
+        )
+    }
+
+    #[test]
+    fn hidden_window_systems_do_not_run() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)));
+        let world = app.world_mut();
+        let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings>();
+        plugin_settings.show_window = false;
+        plugin_settings.show_overlay = true;
+        assert_eq!(shown(world), (false, false));
+        let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings>();
+        plugin_settings.show_window = true;
+        plugin_settings.pinned.insert("PlayerMoved".into());
+        assert_eq!(shown(world), (true, true));
+        world.resource_mut::<LogEventsPluginSettings>().show_overlay = false;
+        assert_eq!(shown(world), (true, false));
+    }
+
+    #[test]
+    fn save_one_event_to_missing_file() {
+        let mut app = App::new();
+        let settings_path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(settings_path)))
+            .add_and_log_event::<Saved>()
+            .add_and_log_event::<NotSaved>();
+        app.finish();
+        app.update();
+        let path = std::env::temp_dir().join(format!(
+            "bevy_log_events_save_one_{}.ron",
+            std::process::id()
+        ));
+        save_event_to_file::<()>(app.world(), &path, type_name::<Saved>()).unwrap();
+        let saved = settings_io::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            saved.events_settings.keys().collect::<Vec<_>>(),
+            [type_name::<Saved>()]
+        );
+    }
+
+    #[test]
+    fn sort_by_registration() {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)))
+            .add_and_log_event::<Saved>()
+            .add_and_log_event::<NotSaved>();
+        app.finish();
+        app.update();
+        let world = app.world();
+        let mut entries: Vec<_> = world.resource::<LogSettingsIds>().iter().collect();
+        let sorted = |mode: SortMode, entries: &mut Vec<(&String, &RegisteredEvent)>| {
+            mode.sort(world, entries);
+            entries
+                .iter()
+                .map(|(name, _)| name.to_string())
+                .collect::<Vec<_>>()
+        };
+        let (saved, not_saved) = (type_name::<Saved>(), type_name::<NotSaved>());
+        assert_eq!(
+            sorted(SortMode::Registration, &mut entries),
+            [saved, not_saved]
+        );
+        assert_eq!(
+            sorted(SortMode::RecentlyRegistered, &mut entries),
+            [not_saved, saved]
+        );
+    }
+
+    #[test]
+    fn reset_waits_for_the_save() {
+        let path =
+            std::env::temp_dir().join(format!("bevy_log_events_reset_{}.ron", std::process::id()));
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(&path)))
+            .add_and_log_event::<Saved>();
+        app.finish();
+        app.update();
+        let name = type_name::<Saved>().to_string();
+        let world = app.world_mut();
+        world.resource_mut::<LoggedEventSettings<Saved>>().enabled = false;
+        world
+            .resource_mut::<LogEventsPluginSettings>()
+            .templates
+            .insert(name.clone(), "{name}".into());
+        save_settings_file::<()>(world, &path).unwrap();
+
+        let mut state = LogEventsWindowState::<()>::default();
+        reset_events(world, &mut state, Some(std::slice::from_ref(&name)));
+        let saved = settings_io::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(world.resource::<LoggedEventSettings<Saved>>().enabled);
+        assert!(world
+            .resource::<LogEventsPluginSettings>()
+            .templates
+            .is_empty());
+        assert!(!saved.events_settings[&name].enabled);
+        assert!(saved.templates.contains_key(&name));
+    }
+
+    #[test]
+    fn hover_texts_from_docs() {
+        let TypeInfo::Struct(info) = EventSettings::type_info() else {
+            panic!("EventSettings is a struct");
+        };
+        let hover_text = |name| field_hover_text(info.field(name).unwrap());
+        assert_eq!(
+            hover_text("enabled"),
+            "Whether the Event will be logged or not."
+        );
+        assert!(hover_text("log_unavailable").starts_with("Only used by log_trigger. If true,"));
+        assert!(!hover_text("format").contains("pretty"));
+        for field in info.iter() {
+            let text = field_hover_text(field);
+            assert!(!text.is_empty(), "{} has no doc comment", field.name());
+            assert!(!text.contains(['[', '`']), "{}", text);
+        }
     }
 }
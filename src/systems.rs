@@ -1,255 +1,2355 @@
 use std::{
-    any::type_name,
-    collections::BTreeMap,
-    error::Error,
+    any::{type_name, Any, TypeId},
+    collections::{BTreeMap, BTreeSet, VecDeque},
     fmt::Write,
-    fs::{create_dir_all, File},
+    hash::{DefaultHasher, Hash, Hasher},
+    marker::PhantomData,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, SystemTime},
 };
 
-use bevy::{ecs::component::ComponentId, log::Level, prelude::*};
+use bevy::{
+    ecs::{
+        component::Tick,
+        schedule::{InternedScheduleLabel, ScheduleLabel, SystemConfigs, SystemSetConfigs},
+        system::{IntoObserverSystem, SystemChangeTick},
+    },
+    input::gamepad::{
+        GamepadAxisChangedEvent, GamepadButtonStateChangedEvent, GamepadConnectionEvent,
+    },
+    log::Level,
+    prelude::*,
+    ptr::Ptr,
+    reflect::{serde::TypedReflectSerializer, ReflectFromPtr, TypeRegistry},
+    time::common_conditions::on_real_timer,
+    utils::{HashMap, HashSet, Instant},
+};
 
-use ron::{de::from_reader, ser::PrettyConfig};
+use regex::Regex;
 
 use crate::{
-    utils::{get_log_settings_by_id, trigger_name, LoggedEventsSettings},
-    EventSettings, LogEventsPlugin, LogEventsPluginSettings, LogEventsSet, LoggedEventSettings,
+    log_breakpoint,
+    log_files::output_path,
+    send_schedule::{
+        send_schedule, track_send_schedule, trigger_schedule, CurrentSchedule, SentSchedules,
+    },
+    settings_io::{self, LoggedEventsSettings},
+    utils::{
+        abbreviated_type_name, get_log_settings_by_id, get_log_settings_mut_by_id, parse_filter,
+        short_type_name, trigger_name, with_level_style, FilterSubject, SettingsId,
+    },
+    EntityLabeler, EventSettings, LevelPalette, LevelStyle, LogBackend, LogEventsError,
+    LogEventsPlugin, LogEventsPluginSettings, LogEventsSet, LogFormat, LogInstance, LogOutput,
+    LoggedEventSettings, NameStyle, ReaderStart, RegisterEventsSet, SavePolicy, SettingsFormat,
+    UiDensity, FILTER_ENV_VAR, LOG_HANDLE_CAPACITY, LOG_TARGET,
 };
 
-#[derive(Resource, Default, Deref, DerefMut)]
-pub(crate) struct LogSettingsIds(BTreeMap<String, ComponentId>);
+pub(crate) struct RegisteredEvent {
+    pub id: SettingsId,
+    pub channel: Option<String>,
+    pub group: Option<&'static str>,
+    /// The order in which the events were registered.
+    pub index: usize,
+    pub stats: Arc<EventStats>,
+}
+
+/// The time the frame started, used as the time of the logs made during the frame so the
+/// clock is not read for each of them.
+pub(crate) struct FrameClock {
+    origin: Instant,
+    /// The time elapsed from `origin` to the start of the frame, in microseconds.
+    elapsed: AtomicU64,
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+            elapsed: AtomicU64::new(0),
+        }
+    }
+}
+
+impl FrameClock {
+    pub(crate) fn now(&self) -> Instant {
+        self.origin + Duration::from_micros(self.elapsed.load(Ordering::Relaxed))
+    }
+
+    fn tick(&self) {
+        let elapsed = self.origin.elapsed().as_micros();
+        self.elapsed
+            .store(elapsed.try_into().unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+}
+
+fn tick_frame_clock<I: LogInstance>(plugin_settings: Res<LogEventsPluginSettings<I>>) {
+    plugin_settings.frame_clock.tick();
+}
+
+/// What happened to an event at runtime. This is shared between its
+/// [LoggedEventSettings] and the [LogSettingsIds] so it can be updated while logging.
+#[derive(Default)]
+pub(crate) struct EventStats(Mutex<EventActivity>);
+
+#[derive(Default)]
+pub(crate) struct EventActivity {
+    pub first_logged: Option<Instant>,
+    pub last_logged: Option<Instant>,
+    pub payload_warned: bool,
+    /// The number of logs made since the start of the run.
+    pub logged: u64,
+    /// The number of logs not made because of the settings limiting them, like
+    /// [max_logs](crate::EventSettings::max_logs).
+    pub suppressed: u64,
+    pub digest: Option<Digest>,
+    /// The payload of the last log, cut to [MAX_PAYLOAD_PREVIEW] bytes, shown in the
+    /// settings window.
+    pub last_payload: Option<String>,
+    /// The last line logged, cut to [MAX_PAYLOAD_PREVIEW] bytes, copied from the settings
+    /// window.
+    pub last_line: Option<String>,
+    /// The entity targeted by the last log, as written in it.
+    pub last_entity: Option<String>,
+    /// The logs gathered during the frame because of
+    /// [aggregate_burst_threshold](crate::EventSettings::aggregate_burst_threshold).
+    pub burst: Vec<GatheredLine>,
+    /// The logs made before the event was registered, like the events triggered while
+    /// the plugins are built, up to [MAX_EARLY_LINES].
+    pub early: Vec<GatheredLine>,
+    /// The number of logs made before the event was registered that did not fit in
+    /// [early](Self::early).
+    pub early_dropped: u64,
+    /// The compiled [entity_filters](LogEventsPluginSettings::entity_filters) of the event,
+    /// with the expression it was compiled from.
+    pub entity_filter: Option<(String, Option<Regex>)>,
+    /// The entities logged since the last frame with [gizmo](EventSettings::gizmo)
+    /// enabled, with the level of their log.
+    #[cfg(feature = "gizmos")]
+    pub gizmo_entities: Vec<(Entity, Level)>,
+    /// When the [sound](LogEventsPluginSettings::sounds) of the event was last played.
+    #[cfg(feature = "audio")]
+    pub last_sound: Option<Instant>,
+    /// The sound to play because of a log made since the last frame.
+    #[cfg(feature = "audio")]
+    pub pending_sound: Option<crate::SoundCue>,
+}
+
+/// The maximum length in bytes of the [last_payload](EventActivity::last_payload) of an
+/// event.
+pub(crate) const MAX_PAYLOAD_PREVIEW: usize = 4096;
+
+/// The maximum number of logs of an event kept until it is registered, the next ones are
+/// dropped.
+pub(crate) const MAX_EARLY_LINES: usize = 1024;
+
+/// The events gathered since the last summary of an event in digest mode.
+pub(crate) struct Digest {
+    start: Instant,
+    count: usize,
+    levels: Vec<Level>,
+    example: String,
+}
+
+impl Digest {
+    fn new(example: &str) -> Self {
+        Self {
+            start: Instant::now(),
+            count: 0,
+            levels: Vec::new(),
+            example: example.to_string(),
+        }
+    }
+
+    fn add(&mut self, level: Level) {
+        self.count += 1;
+        if !self.levels.contains(&level) {
+            self.levels.push(level);
+            self.levels.sort();
+        }
+    }
+
+    /// The memory used by this digest.
+    pub(crate) fn size(&self) -> usize {
+        size_of::<Self>() + self.example.capacity() + self.levels.capacity() * size_of::<Level>()
+    }
+
+    /// The summary is logged at the most severe level of the gathered events.
+    fn level(&self) -> Level {
+        self.levels.first().copied().unwrap_or(Level::INFO)
+    }
+
+    fn summary(&self, name: &str) -> String {
+        let levels: Vec<_> = self.levels.iter().map(Level::as_str).collect();
+        format!(
+            "{}: {} events in the last {:.1}s at level {}, e.g. {}",
+            name,
+            self.count,
+            self.start.elapsed().as_secs_f32(),
+            levels.join("/"),
+            self.example
+        )
+    }
+}
+
+impl EventStats {
+    pub(crate) fn lock(&self) -> MutexGuard<'_, EventActivity> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The events registered with the instance `I` of the [LogEventsPlugin], by name.
+#[derive(Resource, Deref, DerefMut)]
+pub(crate) struct LogSettingsIds<I = ()>(
+    #[deref] BTreeMap<String, RegisteredEvent>,
+    PhantomData<fn() -> I>,
+);
+
+impl<I> Default for LogSettingsIds<I> {
+    fn default() -> Self {
+        Self(BTreeMap::new(), PhantomData)
+    }
+}
+
+/// The systems of the [LogEventsSet] logging the events of one instance of the
+/// [LogEventsPlugin], only run while it is [enabled](LogEventsPluginSettings::enabled).
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct InstanceSet(TypeId);
+
+impl InstanceSet {
+    pub(crate) fn of<I: LogInstance>() -> Self {
+        Self(TypeId::of::<I>())
+    }
+}
+
+/// The channel given to the events registered inside [log_channel](crate::LogEvent::log_channel).
+#[derive(Resource)]
+pub(crate) struct CurrentLogChannel(pub String);
+
+/// The group given to the events registered inside [with_group](crate::LogEvent::with_group).
+#[derive(Resource)]
+pub(crate) struct CurrentLogGroup(pub &'static str);
+
+/// The start given to the events registered inside
+/// [with_reader_start](crate::LogEvent::with_reader_start).
+#[derive(Resource)]
+pub(crate) struct CurrentReaderStart(pub ReaderStart);
 
-impl Plugin for LogEventsPlugin {
+impl<I: LogInstance> Plugin for LogEventsPlugin<I> {
     fn build(&self, app: &mut App) {
-        app.insert_resource(LogEventsPluginSettings::new(self))
-            .insert_resource(LogSettingsIds::default())
-            .configure_sets(Last, LogEventsSet.run_if(plugin_enabled))
-            .add_systems(PostUpdate, save_settings.run_if(on_event::<AppExit>))
-            .add_plugins(crate::settings_window::plugin);
+        let mut plugin_settings = LogEventsPluginSettings::<I>::new(self);
+        plugin_settings.type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        app.register_type::<EventSettings>()
+            .insert_resource(plugin_settings)
+            .insert_resource(LogSettingsIds::<I>::default())
+            .init_resource::<EntityLabeler>()
+            .init_resource::<ComponentHistory>()
+            .insert_resource(LogSchedule::<I>(self.schedule, PhantomData))
+            .configure_sets(self.schedule, self.log_set_config())
+            .add_systems(
+                self.schedule,
+                flush_digests::<I>.in_set(InstanceSet::of::<I>()),
+            )
+            .add_systems(self.schedule, sync_settings::<I>)
+            .add_systems(First, (tick_frame_clock::<I>, check_frame_budget::<I>))
+            .add_systems(
+                Startup,
+                (register_pending::<I>, crate::watch::register_watched::<I>)
+                    .in_set(RegisterEventsSet),
+            )
+            .add_systems(PostUpdate, save_settings::<I>.run_if(on_event::<AppExit>));
+        crate::diagnostics::plugin::<I>(app);
+        crate::log_files::plugin::<I>(app);
+        if let Some(pending) = app.world_mut().remove_resource::<PendingLogSystems<I>>() {
+            for systems in pending.0 {
+                app.add_systems(self.schedule, systems);
+            }
+        }
+        crate::settings_window::plugin::<I>(app, self.add_egui);
+        if self.save_on_panic {
+            install_panic_hook::<I>(app);
+        }
+        if let Some(interval) = self.hot_reload {
+            app.add_systems(
+                self.schedule,
+                hot_reload_settings::<I>.run_if(on_real_timer(interval)),
+            );
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(address) = self.metrics_address {
+            crate::metrics::plugin::<I>(app, address);
+        }
+        #[cfg(feature = "gizmos")]
+        crate::gizmos::plugin::<I>(app);
+        #[cfg(feature = "audio")]
+        crate::audio::plugin::<I>(app);
+        if let Some(capacity) = self.log_history {
+            crate::history::plugin::<I>(app, capacity);
+        }
         // #[cfg(feature = "editor_window")]
         // {
         //     app.add_plugins(crate::editor_window::plugin);
         // }
     }
+
+    fn finish(&self, app: &mut App) {
+        crate::send_schedule::plugin(app);
+        register_pending::<I>(app.world_mut());
+    }
+}
+
+impl<I: LogInstance> LogEventsPlugin<I> {
+    fn log_set_config(&self) -> SystemSetConfigs {
+        let mut config = LogEventsSet.into_configs();
+        for set in &self.after {
+            config = config.after(*set);
+        }
+        for set in &self.before {
+            config = config.before(*set);
+        }
+        (
+            config,
+            InstanceSet::of::<I>()
+                .in_set(LogEventsSet)
+                .run_if(plugin_enabled::<I>),
+        )
+            .into_configs()
+    }
+}
+
+/// The schedule where the [LogEventsSet] of the instance `I` runs.
+#[derive(Resource)]
+struct LogSchedule<I>(InternedScheduleLabel, PhantomData<fn() -> I>);
+
+/// The schedule where the systems of the [LogEventsSet] of the instance `I` must be added.
+pub(crate) fn log_schedule<I: LogInstance>(world: &World) -> InternedScheduleLabel {
+    world
+        .get_resource::<LogSchedule<I>>()
+        .map_or(Last.intern(), |schedule| schedule.0)
+}
+
+/// The systems added before the [LogEventsPlugin] of the instance `I`, added to its
+/// schedule when it is built.
+#[derive(Resource)]
+struct PendingLogSystems<I>(Vec<SystemConfigs>, PhantomData<fn() -> I>);
+
+impl<I> Default for PendingLogSystems<I> {
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
 }
 
-impl LogEventsPluginSettings {
-    fn new(log_plugin: &LogEventsPlugin) -> Self {
-        let path = &log_plugin.settings_path;
-        match Self::load_saved_settings(path) {
+/// Add `systems` to the schedule of the [LogEventsSet] of the instance `I`, once its
+/// [LogEventsPlugin] is added if it is not yet.
+pub(crate) fn add_to_log_schedule<I: LogInstance, M>(
+    app: &mut App,
+    systems: impl IntoSystemConfigs<M>,
+) {
+    match app.world().get_resource::<LogSchedule<I>>() {
+        Some(schedule) => {
+            let schedule = schedule.0;
+            app.add_systems(schedule, systems);
+        }
+        None => app
+            .world_mut()
+            .get_resource_or_init::<PendingLogSystems<I>>()
+            .0
+            .push(systems.into_configs()),
+    }
+}
+
+impl<I: LogInstance> LogEventsPluginSettings<I> {
+    fn new(log_plugin: &LogEventsPlugin<I>) -> Self {
+        let fallback_path = log_plugin
+            .fallback_dir
+            .as_ref()
+            .zip(log_plugin.settings_path.file_name())
+            .map(|(dir, file_name)| dir.join(file_name));
+        let path = match &fallback_path {
+            Some(fallback_path) if is_newer(fallback_path, &log_plugin.settings_path) => {
+                fallback_path
+            }
+            _ => &log_plugin.settings_path,
+        };
+        let format_of = |path: &Path| {
+            log_plugin
+                .format
+                .unwrap_or_else(|| SettingsFormat::from_path(path))
+        };
+        let base = log_plugin
+            .base_path
+            .as_ref()
+            .and_then(|base_path| match settings_io::load_as(base_path, format_of(base_path)) {
+                Ok(base) => Some(base),
+                Err(err) => {
+                    warn!(target: LOG_TARGET, "Error while trying to load the base settings from {:?}: {}. Only the settings from {:?} are used.", base_path, err, path);
+                    None
+                }
+            });
+        let mut new = match Self::load_saved_settings(path, format_of(path), base.as_ref()) {
             Ok(new) => new,
             Err(err) => {
-                warn!(target: "bevy_log_events", "Error while trying to load settings from {:?}: {}. Using default settings instead.", path, err);
-                LogEventsPluginSettings::default(path)
+                warn!(target: LOG_TARGET, "Error while trying to load settings from {:?}: {}. Using default settings instead.", path, err);
+                Self::default(path)
             }
-        }
+        };
+        new.short_names = log_plugin.short_names;
+        new.save_policy = log_plugin.save_policy;
+        new.fallback_path = fallback_path;
+        new.format = log_plugin.format;
+        new.level_style = log_plugin.level_style;
+        new.base_settings = base;
+        new.history = log_plugin.log_history.map(|_| default());
+        new.disabled_groups = log_plugin.disabled_groups.clone();
+        new.reader_start = log_plugin.reader_start;
+        new.env_filter = std::env::var(FILTER_ENV_VAR).ok().and_then(|expression| {
+            parse_filter(&expression)
+                .inspect_err(|err| {
+                    warn!(target: LOG_TARGET, "The {} environment variable is not a valid filter expression: {}", FILTER_ENV_VAR, err);
+                })
+                .ok()
+        });
+        new.backend = log_plugin.backend;
+        new.formatters = log_plugin.formatters.clone();
+        new
     }
 
     fn default(path: &Path) -> Self {
         Self {
             enabled: true,
             show_window: false,
+            show_overlay: false,
+            show_log_viewer: false,
+            min_level: Level::TRACE,
+            window_context: None,
+            channels: BTreeMap::new(),
+            payload_size_warning: None,
+            show_type_info: false,
+            level_palette: LevelPalette::Default,
+            ui_density: UiDensity::Compact,
+            name_style: NameStyle::Full,
+            disabled_groups: BTreeSet::new(),
+            templates: BTreeMap::new(),
+            entity_filters: BTreeMap::new(),
+            sounds: BTreeMap::new(),
+            output_files: BTreeMap::new(),
+            pinned: BTreeSet::new(),
+            favorites: BTreeSet::new(),
+            filter_presets: BTreeMap::new(),
+            break_hook: None,
+            sync_path: None,
+            frame_budget: None,
+            component_history: 0,
+            watch: Vec::new(),
+            renames: BTreeMap::new(),
+            window_available: false,
+            short_names: false,
+            save_policy: SavePolicy::Overwrite,
+            reader_start: ReaderStart::Buffered,
+            backend: LogBackend::Tracing,
+            over_budget: false,
+            save_base: None,
+            base_settings: None,
+            file_settings: None,
+            fallback_path: None,
+            format: None,
+            level_style: LevelStyle::Uppercase,
             saved_settings: path.to_path_buf(),
             previous_settings: BTreeMap::new(),
+            row_widgets: Vec::new(),
+            window_layout: default(),
+            solo: None,
+            history: None,
+            pending_lines: default(),
+            frame_clock: default(),
+            #[cfg(feature = "notifications")]
+            notifier: default(),
+            formatters: BTreeMap::new(),
+            type_registry: default(),
+            env_filter: None,
+            _instance: PhantomData,
         }
     }
 
-    fn load_saved_settings(path: &PathBuf) -> Result<Self, Box<dyn Error>> {
-        let file = File::open(path)?;
-        let saved_settings: LoggedEventsSettings = from_reader(file)?;
+    /// Load the settings file at `path` written in `format`, as an overlay of `base` if
+    /// there is one.
+    fn load_saved_settings(
+        path: &Path,
+        format: SettingsFormat,
+        base: Option<&LoggedEventsSettings>,
+    ) -> Result<Self, LogEventsError> {
+        let file_settings = match base {
+            Some(base) if !path.exists() => base.clone(),
+            base => read_file(path, format, base)?,
+        };
+        let save_base = Some(file_settings.clone());
+        let saved_settings = file_settings.apply_platforms(settings_io::current_platforms());
         let new = Self {
             enabled: saved_settings.plugin_enabled,
-            show_window: false,
+            show_window: saved_settings.window.open,
+            show_overlay: false,
+            show_log_viewer: false,
+            min_level: saved_settings.min_level,
+            window_context: None,
+            channels: saved_settings.channels,
+            payload_size_warning: None,
+            show_type_info: false,
+            level_palette: saved_settings.level_palette,
+            ui_density: UiDensity::Compact,
+            name_style: NameStyle::Full,
+            disabled_groups: BTreeSet::new(),
+            templates: saved_settings.templates,
+            entity_filters: saved_settings.entity_filters,
+            sounds: saved_settings.sounds,
+            output_files: saved_settings.output_files,
+            pinned: saved_settings.pinned,
+            favorites: saved_settings.favorites,
+            filter_presets: saved_settings.filter_presets,
+            break_hook: None,
+            sync_path: None,
+            frame_budget: None,
+            component_history: 0,
+            watch: saved_settings.watch,
+            renames: saved_settings.renames,
+            window_available: false,
+            short_names: false,
+            save_policy: SavePolicy::Overwrite,
+            reader_start: ReaderStart::Buffered,
+            backend: LogBackend::Tracing,
+            over_budget: false,
+            save_base,
+            base_settings: None,
+            file_settings: Some(file_settings),
+            fallback_path: None,
+            format: None,
+            level_style: LevelStyle::Uppercase,
             saved_settings: path.to_path_buf(),
             previous_settings: saved_settings.events_settings,
+            row_widgets: Vec::new(),
+            window_layout: saved_settings.window,
+            solo: None,
+            history: None,
+            pending_lines: default(),
+            frame_clock: default(),
+            #[cfg(feature = "notifications")]
+            notifier: default(),
+            formatters: BTreeMap::new(),
+            type_registry: default(),
+            env_filter: None,
+            _instance: PhantomData,
         };
         Ok(new)
     }
+
+    /// The [SettingsFormat] of the settings file at `path`, the one given to
+    /// [with_format](LogEventsPlugin::with_format) or else the one of its extension.
+    pub(crate) fn format_of(&self, path: &Path) -> SettingsFormat {
+        self.format
+            .unwrap_or_else(|| SettingsFormat::from_path(path))
+    }
+
+    /// Read the settings file at `path` as the plugin does when it starts, over its
+    /// [base](LogEventsPlugin::with_base) if there is one.
+    pub(crate) fn read_file(&self, path: &Path) -> Result<LoggedEventsSettings, LogEventsError> {
+        read_file(path, self.format_of(path), self.base_settings.as_ref())
+    }
+
+    /// Write `settings` in the settings file at `path` as the plugin does when it saves
+    /// them, as an overlay of its [base](LogEventsPlugin::with_base) if there is one.
+    pub(crate) fn write_file(
+        &self,
+        path: &Path,
+        settings: &LoggedEventsSettings,
+    ) -> Result<(), LogEventsError> {
+        write_file(
+            path,
+            self.format_of(path),
+            self.level_style,
+            self.base_settings.as_ref(),
+            settings,
+        )
+    }
+
+    /// Whether an event with these settings must be logged, taking into account the
+    /// plugin and channel settings.
+    pub(crate) fn must_log<E, C>(&self, settings: &LoggedEventSettings<E, C>) -> bool {
+        self.must_log_event(settings, settings.channel(), settings.group())
+    }
+
+    /// Like [must_log](Self::must_log) for an event in `channel` and `group`.
+    pub(crate) fn must_log_event(
+        &self,
+        settings: &EventSettings,
+        channel: Option<&str>,
+        group: Option<&str>,
+    ) -> bool {
+        self.enabled
+            && settings.enabled
+            && (cfg!(debug_assertions) || !settings.debug_only)
+            && settings.level <= self.min_level
+            && channel
+                .and_then(|channel| self.channels.get(channel))
+                .is_none_or(|channel| channel.enabled && settings.level <= channel.level)
+            && group.is_none_or(|group| !self.disabled_groups.contains(group))
+            && (!self.over_budget || settings.level <= Level::INFO)
+    }
 }
 
-fn plugin_enabled(plugin_settings: Res<LogEventsPluginSettings>) -> bool {
+fn plugin_enabled<I: LogInstance>(plugin_settings: Res<LogEventsPluginSettings<I>>) -> bool {
     plugin_settings.enabled
 }
 
-pub(crate) fn register_event<E: Event>(world: &mut World) {
-    let name = type_name::<E>().to_string();
-    world.resource_scope(|world, plugin_settings: Mut<LogEventsPluginSettings>| {
-        if let Some(previous) = plugin_settings.previous_settings.get(&name) {
-            let mut event_settings = world.resource_mut::<LoggedEventSettings<E>>();
-            **event_settings = *previous;
-        }
-    });
-    world.resource_scope(|world, mut log_settings_ids: Mut<LogSettingsIds>| {
-        let id = world
-            .components()
-            .resource_id::<LoggedEventSettings<E>>()
-            .unwrap();
-        log_settings_ids.insert(name, id);
+pub(crate) fn register_settings<E, C, I: LogInstance>(world: &mut World, name: String)
+where
+    E: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    let name = settings_name::<E, C, I>(world, name);
+    let (channel, group, stats) = world.resource_scope(
+        |world, mut plugin_settings: Mut<LogEventsPluginSettings<I>>| {
+            // Restoring the saved settings is not a change made by the user, so it must
+            // not trigger the change detection of these resources.
+            let mut event_settings = world.resource_mut::<LoggedEventSettings<E, C>>();
+            event_settings.bypass_change_detection().name = name.clone();
+            if let Some(previous) = plugin_settings.previous_settings.get(&name) {
+                **event_settings.bypass_change_detection() = previous.clone();
+            }
+            let channel = event_settings.channel().map(str::to_string);
+            if let Some(env_filter) = &plugin_settings.env_filter {
+                let enabled = env_filter.matches(&FilterSubject {
+                    name: &name,
+                    settings: &event_settings,
+                    channel: channel.as_deref(),
+                    group: event_settings.group(),
+                });
+                event_settings.bypass_change_detection().enabled = enabled;
+            }
+            if let Some(channel) = &channel {
+                plugin_settings
+                    .bypass_change_detection()
+                    .channels
+                    .entry(channel.clone())
+                    .or_default();
+            }
+            (
+                channel,
+                event_settings.group(),
+                event_settings.stats.clone(),
+            )
+        },
+    );
+    world.resource_scope(|world, mut log_settings_ids: Mut<LogSettingsIds<I>>| {
+        let id = SettingsId::of::<E, C>(world).unwrap();
+        let index = log_settings_ids.len();
+        log_settings_ids.insert(
+            name,
+            RegisteredEvent {
+                id,
+                channel,
+                group,
+                index,
+                stats,
+            },
+        );
     });
+    log_early_lines::<E, C, I>(world);
 }
 
-pub(crate) fn register_component<E: Event, C: Component>(world: &mut World) {
-    let name = trigger_name::<E, C>();
-    world.resource_scope(|world, plugin_settings: Mut<LogEventsPluginSettings>| {
-        if let Some(previous) = plugin_settings.previous_settings.get(&name) {
-            let mut event_settings = world.resource_mut::<LoggedEventSettings<E, C>>();
-            **event_settings = *previous;
-        }
-    });
-    world.resource_scope(|world, mut log_settings_ids: Mut<LogSettingsIds>| {
-        let id = world
-            .components()
-            .resource_id::<LoggedEventSettings<E, C>>()
-            .unwrap();
-        log_settings_ids.insert(name, id);
-    });
+/// Log the lines of `E`, `C` gathered by [emit] before it was registered, now that its
+/// saved settings are restored.
+fn log_early_lines<E, C, I: LogInstance>(world: &World)
+where
+    E: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    let settings = world.resource::<LoggedEventSettings<E, C>>();
+    let (early, dropped) = {
+        let mut activity = settings.stats.lock();
+        let dropped = std::mem::take(&mut activity.early_dropped);
+        (std::mem::take(&mut activity.early), dropped)
+    };
+    if !plugin_settings.must_log(settings) {
+        return;
+    }
+    if dropped > 0 {
+        warn!(
+            target: LOG_TARGET,
+            "{} logs of {} made before it was registered were dropped, only the first {} are kept",
+            dropped,
+            settings.name(),
+            MAX_EARLY_LINES
+        );
+    }
+    for line in early {
+        emit(plugin_settings, settings, line.named(settings.name()));
+    }
+}
+
+/// The [Observer] logging the events of a [LoggedEventSettings]. It is only spawned
+/// while these events can be logged.
+#[derive(Resource)]
+struct LogObserver<E, C> {
+    entity: Option<Entity>,
+    spawn: Box<dyn Fn(&mut Commands) -> Entity + Send + Sync>,
+    _phantom: PhantomData<(E, C)>,
 }
 
-fn log(level: Level, to_log: &str) {
-    match level {
-        Level::ERROR => error!(target: "bevy_log_events", "{}", to_log),
-        Level::WARN => warn!(target: "bevy_log_events", "{}", to_log),
-        Level::INFO => info!(target: "bevy_log_events", "{}", to_log),
-        Level::DEBUG => debug!(target: "bevy_log_events", "{}", to_log),
-        Level::TRACE => trace!(target: "bevy_log_events", "{}", to_log),
+fn toggle_observer<E, C, I: LogInstance>(
+    mut commands: Commands,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E, C>>,
+    mut observer: ResMut<LogObserver<E, C>>,
+) where
+    E: Event,
+    C: Bundle,
+{
+    match (plugin_settings.must_log(&settings), observer.entity) {
+        (true, None) => observer.entity = Some((observer.spawn)(&mut commands)),
+        (false, Some(entity)) => {
+            commands.entity(entity).despawn();
+            observer.entity = None;
+        }
+        _ => {}
     }
 }
 
-fn format_and_log_event<E>(settings: &EventSettings, event: &E)
-where
-    E: std::fmt::Debug,
+/// Add the systems spawning and despawning the [Observer] running `observer_system`
+/// depending on whether its events can be logged.
+///
+/// The [Observer] is spawned right away so the events triggered before the [Startup]
+/// systems are not missed, it is despawned in [Startup] if they can not be logged.
+pub(crate) fn add_log_observer<I: LogInstance, E, C, M>(
+    app: &mut App,
+    observer_system: impl IntoObserverSystem<E, C, M> + Clone + Sync,
+    name: String,
+) where
+    E: Event,
+    C: Bundle,
 {
-    let name = type_name::<E>();
-    let to_log = if settings.pretty {
-        format!("{}: {:#?}", name, event)
-    } else {
-        format!("{}: {:?}", name, event)
-    };
-    log(settings.level, &to_log);
+    let mut observer = LogObserver::<E, C>::new(observer_system, name);
+    let world = app.world_mut();
+    observer.entity = Some((observer.spawn)(&mut world.commands()));
+    world.flush();
+    app.insert_resource(observer)
+        .add_systems(Startup, toggle_observer::<E, C, I>.after(RegisterEventsSet))
+        .add_systems(PreUpdate, toggle_observer::<E, C, I>);
 }
 
-fn format_entity_and_object<T>(
-    settings: &EventSettings,
-    event_name: &str,
-    entity_name: &Option<&Name>,
-    entity: Entity,
-    object: &T,
-) -> Result<String, Box<dyn Error>>
+/// Like [add_log_observer] but for an [App] that is already running. The [Observer]
+/// will be spawned during the next [PreUpdate].
+pub(crate) fn add_runtime_log_observer<I: LogInstance, E, C, M>(
+    world: &mut World,
+    observer_system: impl IntoObserverSystem<E, C, M> + Clone + Sync,
+    name: String,
+) where
+    E: Event,
+    C: Bundle,
+{
+    world.insert_resource(LogObserver::<E, C>::new(observer_system, name));
+    world
+        .resource_mut::<Schedules>()
+        .add_systems(PreUpdate, toggle_observer::<E, C, I>);
+}
+
+impl<E, C> LogObserver<E, C> {
+    fn new<M>(
+        observer_system: impl IntoObserverSystem<E, C, M> + Clone + Sync,
+        name: String,
+    ) -> Self
+    where
+        E: Event,
+        C: Bundle,
+    {
+        let spawn = move |commands: &mut Commands| {
+            commands
+                .spawn((
+                    Observer::new(observer_system.clone()),
+                    Name::new(name.clone()),
+                ))
+                .id()
+        };
+        Self {
+            entity: None,
+            spawn: Box::new(spawn),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// The name under which the settings of `E` are saved: the alias given at registration if
+/// any, or else `name` shortened if [with_short_names](LogEventsPlugin::with_short_names)
+/// was used and no other event has the same short name.
+fn settings_name<E, C, I: LogInstance>(world: &World, name: String) -> String
 where
-    T: std::fmt::Debug,
+    E: Send + Sync + 'static,
+    C: Send + Sync + 'static,
 {
-    let mut to_log = String::new();
-    to_log.write_fmt(format_args!("{} on ", event_name))?;
-    if let Some(name) = entity_name {
-        to_log.write_fmt(format_args!("{}({}): ", name, entity))?;
-    } else {
-        to_log.write_fmt(format_args!("{}: ", entity))?;
+    let alias = world.resource::<LoggedEventSettings<E, C>>().name();
+    if !alias.is_empty() {
+        return alias.to_string();
+    }
+    if !world.resource::<LogEventsPluginSettings<I>>().short_names {
+        return name;
     }
-    if settings.pretty {
-        to_log.write_fmt(format_args!("{:#?}", object))?;
+    let short = short_type_name(&name);
+    let clashes = world
+        .get_resource::<ShortNameClashes<I>>()
+        .is_some_and(|clashes| clashes.0.contains(&short));
+    // An event registered at runtime can not take the short name of an event already
+    // registered, as its settings are already saved under it.
+    if clashes || world.resource::<LogSettingsIds<I>>().contains_key(&short) {
+        warn!(target: LOG_TARGET, "The short name \"{}\" of \"{}\" is shared with another event, its full name is used instead", short, name);
+        name
     } else {
-        to_log.write_fmt(format_args!("{:?}", object))?;
+        short
     }
-    Ok(to_log)
 }
 
-pub(crate) fn log_event<E>(settings: Res<LoggedEventSettings<E>>, mut events: EventReader<E>)
-where
-    E: Event + std::fmt::Debug,
-{
-    if !settings.enabled {
+/// The registrations of the instance `I` waiting for the plugins to be finished with the
+/// name of their event, see [RegisterEventsSet].
+#[derive(Resource)]
+struct PendingRegistrations<I>(Vec<(String, RegisterFn)>, PhantomData<fn() -> I>);
+
+impl<I> Default for PendingRegistrations<I> {
+    fn default() -> Self {
+        Self(Vec::new(), PhantomData)
+    }
+}
+
+type RegisterFn = fn(&mut World);
+
+/// The short names shared by several events registered with the [App] for the instance
+/// `I`, which all keep their full name whatever the order they were registered in.
+#[derive(Resource)]
+struct ShortNameClashes<I>(HashSet<String>, PhantomData<fn() -> I>);
+
+impl<I> Default for ShortNameClashes<I> {
+    fn default() -> Self {
+        Self(HashSet::new(), PhantomData)
+    }
+}
+
+/// Run `register`, registering the event `name` with the instance `I`, when the plugins
+/// are finished, or in the [RegisterEventsSet] if they are not.
+pub(crate) fn queue_registration<I: LogInstance>(
+    app: &mut App,
+    name: impl Into<String>,
+    register: fn(&mut World),
+) {
+    app.world_mut()
+        .get_resource_or_init::<PendingRegistrations<I>>()
+        .0
+        .push((name.into(), register));
+}
+
+fn register_pending<I: LogInstance>(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<PendingRegistrations<I>>() else {
         return;
+    };
+    let pending = std::mem::take(&mut pending.0);
+    let mut short_names = HashMap::<String, usize>::default();
+    for (name, _) in &pending {
+        *short_names.entry(short_type_name(name)).or_default() += 1;
     }
-    for event in events.read() {
-        format_and_log_event(&settings, event);
+    world
+        .get_resource_or_init::<ShortNameClashes<I>>()
+        .0
+        .extend(
+            short_names
+                .into_iter()
+                .filter(|(_, count)| *count > 1)
+                .map(|(short, _)| short),
+        );
+    for (_, register) in pending {
+        register(world);
     }
 }
 
-pub(crate) fn log_triggered<E>(
-    trigger: Trigger<E>,
-    plugin_settings: Res<LogEventsPluginSettings>,
-    settings: Res<LoggedEventSettings<E>>,
-    names: Query<&Name>,
-) where
-    E: Event + std::fmt::Debug,
-{
-    if !plugin_settings.enabled || !settings.enabled {
+pub(crate) fn register_event<E: Event, I: LogInstance>(world: &mut World) {
+    register_settings::<E, (), I>(world, type_name::<E>().to_string());
+}
+
+/// Like [register_event] for the events read with an [EventReader].
+pub(crate) fn register_sent_event<E: Event, I: LogInstance>(world: &mut World) {
+    register_event::<E, I>(world);
+    track_send_schedule::<E, I>(world);
+    let schedule = log_schedule::<I>(world);
+    world
+        .resource_mut::<Schedules>()
+        .add_systems(schedule, drain_log_handles::<E>.after(LogEventsSet));
+}
+
+/// Drop the events sent through the [LogHandle](crate::LogHandle)s of `E` that were not
+/// logged, like while the plugin is disabled, and warn about the ones dropped because
+/// their queue was full.
+fn drain_log_handles<E: Event>(settings: Res<LoggedEventSettings<E>>) {
+    let Some(queue) = &settings.queue else {
         return;
+    };
+    queue.receiver.try_iter().for_each(drop);
+    let dropped = queue.dropped.swap(0, Ordering::Relaxed);
+    if dropped > 0 {
+        warn!(
+            target: LOG_TARGET,
+            "{} events of {} sent through a LogHandle were dropped as more than {} were waiting",
+            dropped,
+            settings.name(),
+            LOG_HANDLE_CAPACITY
+        );
     }
-    let entity = trigger.entity();
-    let event = trigger.event();
-    if entity != Entity::PLACEHOLDER {
-        let name = names.get(entity).ok();
-        if let Ok(to_log) =
-            format_entity_and_object::<E>(&settings, type_name::<E>(), &name, entity, event)
-        {
-            log(settings.level, &to_log);
+}
+
+pub(crate) fn register_component<E: Event, C: Component, I: LogInstance>(world: &mut World) {
+    register_settings::<E, C, I>(world, trigger_name::<E, C>());
+}
+
+fn log(backend: LogBackend, level: Level, to_log: &str) {
+    match backend {
+        LogBackend::Tracing => match level {
+            Level::ERROR => error!(target: LOG_TARGET, "{}", to_log),
+            Level::WARN => warn!(target: LOG_TARGET, "{}", to_log),
+            Level::INFO => info!(target: LOG_TARGET, "{}", to_log),
+            Level::DEBUG => debug!(target: LOG_TARGET, "{}", to_log),
+            Level::TRACE => trace!(target: LOG_TARGET, "{}", to_log),
+        },
+        #[cfg(feature = "log-compat")]
+        LogBackend::Log => {
+            let level = match level {
+                Level::ERROR => log::Level::Error,
+                Level::WARN => log::Level::Warn,
+                Level::INFO => log::Level::Info,
+                Level::DEBUG => log::Level::Debug,
+                Level::TRACE => log::Level::Trace,
+            };
+            log::log!(target: LOG_TARGET, level, "{}", to_log);
+        }
+    }
+}
+
+/// A log split in the parts that can be used in the template of an event.
+pub(crate) struct LogLine<'a> {
+    pub name: &'a str,
+    pub entity: Option<String>,
+    pub payload: String,
+    /// The template used when the event has no template of its own.
+    pub default_template: &'static str,
+    /// When the event was sent, if it is known and is not now.
+    pub emitted: Option<Instant>,
+    /// The [Entity] the event targets or the component is on, for the
+    /// [LogHistory](crate::history::LogHistory).
+    pub target: Option<Entity>,
+}
+
+/// A [LogLine] gathered to be logged later, without the name of its event.
+pub(crate) struct GatheredLine {
+    pub entity: Option<String>,
+    pub payload: String,
+    target: Option<Entity>,
+}
+
+impl GatheredLine {
+    fn named(self, name: &str) -> LogLine<'_> {
+        LogLine {
+            target: self.target,
+            ..LogLine::new(name, self.entity, self.payload)
+        }
+    }
+}
+
+const EVENT_TEMPLATE: &str = "{name}: {payload}";
+const ENTITY_TEMPLATE: &str = "{name} on {entity}: {payload}";
+
+impl<'a> LogLine<'a> {
+    pub(crate) fn new(name: &'a str, entity: Option<String>, payload: String) -> Self {
+        let default_template = if entity.is_some() {
+            ENTITY_TEMPLATE
+        } else {
+            EVENT_TEMPLATE
+        };
+        Self {
+            name,
+            entity,
+            payload,
+            default_template,
+            emitted: None,
+            target: None,
         }
+    }
+
+    /// Note that the event targets `entity`, unless it is the [Entity::PLACEHOLDER] of
+    /// the triggers without target.
+    pub(crate) fn with_target(self, entity: Entity) -> Self {
+        Self {
+            target: (entity != Entity::PLACEHOLDER).then_some(entity),
+            ..self
+        }
+    }
+
+    fn gathered(self) -> GatheredLine {
+        GatheredLine {
+            entity: self.entity,
+            payload: self.payload,
+            target: self.target,
+        }
+    }
+
+    /// Replace the placeholders of `template` with the parts of this log, showing its
+    /// name in `name_style`. Unknown placeholders are kept as they are.
+    fn render(
+        &self,
+        template: &str,
+        level: Level,
+        name_style: NameStyle,
+    ) -> Result<String, LogEventsError> {
+        let mut to_log = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            to_log.write_str(&rest[..start])?;
+            rest = &rest[start..];
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+            match &rest[1..end] {
+                "name" => to_log.write_str(&name_style.format_name(self.name))?,
+                "level" => to_log.write_str(level.as_str())?,
+                "entity" => to_log.write_str(self.entity.as_deref().unwrap_or("-"))?,
+                "payload" => to_log.write_str(&self.payload)?,
+                _ => to_log.write_str(&rest[..=end])?,
+            }
+            rest = &rest[end + 1..];
+        }
+        to_log.write_str(rest)?;
+        Ok(to_log)
+    }
+}
+
+impl NameStyle {
+    /// Show the event `name` in this style.
+    pub(crate) fn format_name(self, name: &str) -> String {
+        match self {
+            NameStyle::Full => name.to_string(),
+            NameStyle::Abbreviated => abbreviated_type_name(name),
+            NameStyle::Short => short_type_name(name),
+        }
+    }
+}
+
+/// Like [emit], but gathers `line` to be logged by [flush_burst] at the end of the frame
+/// when [aggregate_burst_threshold](EventSettings::aggregate_burst_threshold) is set.
+fn emit_or_gather<E: 'static, C: 'static, I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    settings: &LoggedEventSettings<E, C>,
+    line: LogLine,
+) {
+    if settings.aggregate_burst_threshold.is_some() {
+        settings.stats.lock().burst.push(line.gathered());
     } else {
-        format_and_log_event(&settings, event);
+        emit(plugin_settings, settings, line);
     }
 }
 
-pub(crate) fn log_component<E, C>(
-    trigger: Trigger<E, C>,
-    plugin_settings: Res<LogEventsPluginSettings>,
+/// Log the logs of the component trigger `E`, `C` gathered during the frame, in a single
+/// line if there are at least
+/// [aggregate_burst_threshold](EventSettings::aggregate_burst_threshold) of them.
+pub(crate) fn flush_burst<E: Event, C: Component, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
     settings: Res<LoggedEventSettings<E, C>>,
-    query: Query<(&C, Option<&Name>)>,
-) where
-    E: Event,
-    C: Component + std::fmt::Debug,
-{
-    if !plugin_settings.enabled || !settings.enabled {
+) {
+    let burst = std::mem::take(&mut settings.stats.lock().burst);
+    if burst.is_empty() {
         return;
     }
-    let entity = trigger.entity();
-    if let Ok((component, name)) = query.get(entity) {
-        if let Ok(to_log) = format_entity_and_object::<C>(
-            &settings,
-            &trigger_name::<E, C>(),
-            &name,
-            entity,
-            component,
-        ) {
-            log(settings.level, &to_log);
+    let threshold = settings.aggregate_burst_threshold.unwrap_or(u32::MAX);
+    if burst.len() >= threshold as usize {
+        let first = &burst[0];
+        let line = LogLine::new(
+            settings.name(),
+            Some(format!("{} entities", burst.len())),
+            format!(
+                "first {}, {}",
+                first.entity.as_deref().unwrap_or("-"),
+                first.payload
+            ),
+        );
+        emit(&plugin_settings, &settings, line);
+    } else {
+        for line in burst {
+            emit(&plugin_settings, &settings, line.named(settings.name()));
         }
     }
 }
 
-fn serialize_settings(
-    path: &PathBuf,
-    to_serialize: LoggedEventsSettings,
-) -> Result<(), Box<dyn Error>> {
-    if let Some(parent) = path.parent() {
-        create_dir_all(parent)?;
+/// Log `line` as described by the settings of its event and update its [EventStats].
+pub(crate) fn emit<E: 'static, C: 'static, I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    settings: &LoggedEventSettings<E, C>,
+    line: LogLine,
+) {
+    if settings.name().is_empty() {
+        let mut activity = settings.stats.lock();
+        if activity.early.len() < MAX_EARLY_LINES {
+            activity.early.push(line.gathered());
+        } else {
+            activity.early_dropped += 1;
+        }
+        return;
+    }
+    let template = plugin_settings
+        .templates
+        .get(line.name)
+        .map_or(line.default_template, String::as_str);
+    let mut activity = settings.stats.lock();
+    if settings
+        .max_logs
+        .is_some_and(|max| activity.logged >= u64::from(max))
+    {
+        activity.suppressed += 1;
+        return;
+    }
+    let Ok(mut to_log) = line.render(template, settings.level, plugin_settings.name_style) else {
+        return;
+    };
+    let now = plugin_settings.frame_clock.now();
+    activity.logged += 1;
+    activity.first_logged.get_or_insert(now);
+    activity.last_logged = Some(now);
+    set_last_payload(
+        activity.last_payload.get_or_insert_with(String::new),
+        &line.payload,
+    );
+    check_payload_size(
+        plugin_settings,
+        line.name,
+        &to_log,
+        &mut activity.payload_warned,
+    );
+    if plugin_settings.show_type_info {
+        to_log = if TypeId::of::<C>() == TypeId::of::<()>() {
+            append_type_info::<E>(&to_log)
+        } else {
+            append_type_info::<C>(&to_log)
+        };
+    }
+    set_last_payload(activity.last_line.get_or_insert_with(String::new), &to_log);
+    activity.last_entity.clone_from(&line.entity);
+    if settings.break_on_log {
+        if let Some(hook) = &plugin_settings.break_hook {
+            hook(line.name, &to_log);
+        }
+        log_breakpoint(line.name, &to_log);
+    }
+    #[cfg(feature = "notifications")]
+    if settings.notify {
+        plugin_settings.notifier.notify(line.name, &to_log);
+    }
+    #[cfg(feature = "audio")]
+    if let Some(sound) = plugin_settings.sounds.get(line.name) {
+        if activity
+            .last_sound
+            .is_none_or(|last| now.duration_since(last).as_secs_f32() >= sound.cooldown)
+        {
+            activity.last_sound = Some(now);
+            activity.pending_sound = Some(sound.clone());
+        }
+    }
+    if settings.digest.is_some() {
+        activity
+            .digest
+            .get_or_insert_with(|| Digest::new(&to_log))
+            .add(settings.level);
+    } else {
+        if settings.output != LogOutput::File {
+            log(plugin_settings.backend, settings.level, &to_log);
+        }
+        if settings.output != LogOutput::Tracing {
+            plugin_settings.pending_lines.push(
+                output_path(plugin_settings, line.name),
+                settings.level,
+                &to_log,
+            );
+        }
+        if let Some(history) = &plugin_settings.history {
+            history.push(&line, settings.level, &to_log);
+        }
+    }
+    if settings.max_logs.map(u64::from) == Some(activity.logged) {
+        log(
+            plugin_settings.backend,
+            settings.level,
+            &format!(
+                "{}: cap of {} logs reached, it will not be logged anymore",
+                line.name, activity.logged
+            ),
+        );
     }
-    let mut file = File::create(path)?;
-    let config = PrettyConfig::default().struct_names(true);
-    let serialized = ron::ser::to_string_pretty(&to_serialize, config)?;
-    std::io::Write::write_all(&mut file, serialized.as_bytes())?;
-    Ok(())
 }
 
-fn save_settings(world: &mut World) {
-    let log_settings_ids = world.resource::<LogSettingsIds>();
-    let mut all_settings = BTreeMap::new();
-    for (name, id) in log_settings_ids.iter() {
-        let event_settings = get_log_settings_by_id(world, id);
-        all_settings.insert(name.clone(), *event_settings);
+/// Replace `last_payload` with `payload`, cut to [MAX_PAYLOAD_PREVIEW] bytes.
+fn set_last_payload(last_payload: &mut String, payload: &str) {
+    let mut end = payload.len().min(MAX_PAYLOAD_PREVIEW);
+    while !payload.is_char_boundary(end) {
+        end -= 1;
     }
-    let plugin_settings = world.resource::<LogEventsPluginSettings>();
-    let to_serialize = LoggedEventsSettings {
-        plugin_enabled: plugin_settings.enabled,
-        events_settings: all_settings,
-    };
-    let path = plugin_settings.saved_settings.clone();
-    if let Err(e) = serialize_settings(&path, to_serialize) {
-        error!(
-            target: "bevy_log_events",
-            "Could not save {} at {:?} due to {:?}",
-            type_name::<LoggedEventsSettings>(),
-            path,
-            e
+    last_payload.clear();
+    last_payload.push_str(&payload[..end]);
+    if end < payload.len() {
+        last_payload.push('…');
+    }
+}
+
+fn append_type_info<T: 'static>(to_log: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<T>().hash(&mut hasher);
+    format!(
+        "{} [size={}B type=0x{:016x}]",
+        to_log,
+        size_of::<T>(),
+        hasher.finish()
+    )
+}
+
+/// Log the summary of the events in digest mode whose period is over, or which left
+/// the digest mode.
+fn flush_digests<I: LogInstance>(world: &World) {
+    let backend = world.resource::<LogEventsPluginSettings<I>>().backend;
+    for (name, registered) in world.resource::<LogSettingsIds<I>>().iter() {
+        let Some(settings) = get_log_settings_by_id(world, &registered.id) else {
+            continue;
+        };
+        let mut activity = registered.stats.lock();
+        let Some(digest) = &activity.digest else {
+            continue;
+        };
+        if settings
+            .digest
+            .is_none_or(|period| digest.start.elapsed().as_secs_f32() >= period)
+        {
+            log(backend, digest.level(), &digest.summary(name));
+            activity.digest = None;
+        }
+    }
+}
+
+/// Warn once if `to_log` exceeds the payload size threshold of the plugin.
+fn check_payload_size<I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    name: &str,
+    to_log: &str,
+    warned: &mut bool,
+) {
+    let Some(threshold) = plugin_settings.payload_size_warning else {
+        return;
+    };
+    if !*warned && to_log.len() > threshold {
+        *warned = true;
+        warn!(
+            target: LOG_TARGET,
+            "The log of {} is {} bytes long which exceeds the threshold of {} bytes. \
+            Consider using the compact format or logging it less often.",
+            name,
+            to_log.len(),
+            threshold
+        );
+    }
+}
+
+/// A value turned into text by [format_payload], either typed or reflected.
+pub(crate) trait Payload: std::fmt::Debug {
+    /// The value as its concrete type, to give it to a [Formatter](crate::Formatter).
+    fn as_any(&self) -> Option<&dyn Any>;
+
+    /// The value as a reflected one, if its type is registered in `registry`.
+    fn as_reflected<'a>(&'a self, registry: &TypeRegistry) -> Option<&'a dyn PartialReflect>;
+}
+
+impl<T: std::fmt::Debug + 'static> Payload for T {
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+
+    fn as_reflected<'a>(&'a self, registry: &TypeRegistry) -> Option<&'a dyn PartialReflect> {
+        let from_ptr = registry.get_type_data::<ReflectFromPtr>(TypeId::of::<T>())?;
+        // SAFETY: `from_ptr` is the one registered for `T`, which is the type of `self`.
+        let reflected = unsafe { from_ptr.as_reflect(Ptr::from(self)) };
+        Some(reflected.as_partial_reflect())
+    }
+}
+
+impl Payload for dyn PartialReflect {
+    fn as_any(&self) -> Option<&dyn Any> {
+        self.try_as_reflect().map(Reflect::as_any)
+    }
+
+    fn as_reflected<'a>(&'a self, _registry: &TypeRegistry) -> Option<&'a dyn PartialReflect> {
+        Some(self)
+    }
+}
+
+impl Payload for dyn Reflect {
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(Reflect::as_any(self))
+    }
+
+    fn as_reflected<'a>(&'a self, _registry: &TypeRegistry) -> Option<&'a dyn PartialReflect> {
+        Some(self.as_partial_reflect())
+    }
+}
+
+/// Turn `payload` into text in the [LogFormat] of `settings`. The formats that can not
+/// be used for it fall back to [LogFormat::Compact].
+pub(crate) fn format_payload<T, I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    settings: &EventSettings,
+    payload: &T,
+) -> String
+where
+    T: Payload + ?Sized,
+{
+    let formatted = match &settings.format {
+        LogFormat::Compact => None,
+        LogFormat::Pretty => Some(format!("{:#?}", payload)),
+        LogFormat::Json => {
+            let registry = plugin_settings.type_registry.read();
+            payload.as_reflected(&registry).and_then(|reflected| {
+                serde_json::to_string(&TypedReflectSerializer::new(reflected, &registry)).ok()
+            })
+        }
+        format => payload.as_any().and_then(|value| {
+            plugin_settings
+                .formatters
+                .get(&(value.type_id(), format.to_string()))
+                .map(|formatter| formatter(value))
+        }),
+    };
+    formatted.unwrap_or_else(|| format!("{:?}", payload))
+}
+
+/// The label given to `entity` by the [EntityLabeler].
+pub(crate) fn entity_label(
+    labeler: &EntityLabeler,
+    entities: &Query<EntityRef>,
+    entity: Entity,
+) -> String {
+    labeler.display(entities, entity).to_string()
+}
+
+pub(crate) fn log_event<E, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+    mut events: EventReader<E>,
+    mut sent: Option<ResMut<SentSchedules<E>>>,
+) where
+    E: Event + std::fmt::Debug,
+{
+    let queued = queued_events(&settings);
+    if !plugin_settings.must_log(&settings) {
+        queued.for_each(drop);
+        return;
+    }
+    let queued: Vec<_> = queued.collect();
+    for (index, (event, id)) in read_with_ids(&mut events, &settings, &queued).enumerate() {
+        let mut line = LogLine::new(
+            settings.name(),
+            None,
+            format_payload(&plugin_settings, &settings, event),
+        );
+        append_origin(&mut line, &settings, index, send_schedule(&mut sent, id));
+        emit(&plugin_settings, &settings, line);
+    }
+}
+
+/// The events read by `events` with their id, followed by the `queued` events. The events
+/// sent before the `first_id` of `settings` are skipped, see [ReaderStart].
+fn read_with_ids<'a, E: Event>(
+    events: &'a mut EventReader<E>,
+    settings: &LoggedEventSettings<E>,
+    queued: &'a [E],
+) -> impl Iterator<Item = (&'a E, Option<usize>)> {
+    let first_id = settings.first_id;
+    events
+        .read_with_id()
+        .filter(move |(_, id)| id.id >= first_id)
+        .map(|(event, id)| (event, Some(id.id)))
+        .chain(queued.iter().map(|event| (event, None)))
+}
+
+/// Append to the log of an event its position among the events of its type sent during
+/// the frame and the schedule in which it was sent, as asked by its
+/// [frame_index](EventSettings::frame_index) and
+/// [send_schedule](EventSettings::send_schedule) settings, and note when it was sent.
+fn append_origin(
+    line: &mut LogLine,
+    settings: &EventSettings,
+    index: usize,
+    sent: Option<(InternedScheduleLabel, Instant)>,
+) {
+    if settings.frame_index {
+        line.payload
+            .push_str(&format!(" #{} this frame", index + 1));
+    }
+    line.emitted = sent.map(|(_, ended)| ended);
+    if let Some((schedule, _)) = sent.filter(|_| settings.send_schedule) {
+        line.payload.push_str(&format!(" (sent in {:?})", schedule));
+    }
+}
+
+/// Append to the log of a trigger the schedule in which it was triggered, as asked by its
+/// [send_schedule](EventSettings::send_schedule) setting.
+fn append_trigger_schedule(
+    line: &mut LogLine,
+    settings: &EventSettings,
+    schedule: Option<InternedScheduleLabel>,
+) {
+    if let Some(schedule) = schedule.filter(|_| settings.send_schedule) {
+        line.payload
+            .push_str(&format!(" (triggered in {:?})", schedule));
+    }
+}
+
+/// The gamepad events logged by [log_gamepad_events](crate::LogEvent::log_gamepad_events).
+pub(crate) trait GamepadEvent: Event + std::fmt::Debug {
+    fn gamepad(&self) -> Entity;
+}
+
+impl GamepadEvent for GamepadConnectionEvent {
+    fn gamepad(&self) -> Entity {
+        self.gamepad
+    }
+}
+
+impl GamepadEvent for GamepadButtonStateChangedEvent {
+    fn gamepad(&self) -> Entity {
+        self.entity
+    }
+}
+
+impl GamepadEvent for GamepadAxisChangedEvent {
+    fn gamepad(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// Describe a gamepad with its label and device ids instead of its bare [Entity].
+fn gamepad_label(
+    labeler: &EntityLabeler,
+    gamepads: &Query<(EntityRef, Option<&Gamepad>)>,
+    entity: Entity,
+) -> String {
+    let Ok((entity, gamepad)) = gamepads.get(entity) else {
+        return format!("{}", entity);
+    };
+    let mut label = labeler.label(entity);
+    if let Some(vendor_id) = gamepad.and_then(Gamepad::vendor_id) {
+        label.push_str(&format!(" vendor 0x{:04x}", vendor_id));
+    }
+    if let Some(product_id) = gamepad.and_then(Gamepad::product_id) {
+        label.push_str(&format!(" product 0x{:04x}", product_id));
+    }
+    label
+}
+
+pub(crate) fn log_gamepad_event<E, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+    mut events: EventReader<E>,
+    mut sent: Option<ResMut<SentSchedules<E>>>,
+    labeler: Res<EntityLabeler>,
+    gamepads: Query<(EntityRef, Option<&Gamepad>)>,
+) where
+    E: GamepadEvent,
+{
+    let queued = queued_events(&settings);
+    if !plugin_settings.must_log(&settings) {
+        queued.for_each(drop);
+        return;
+    }
+    let queued: Vec<_> = queued.collect();
+    for (index, (event, id)) in read_with_ids(&mut events, &settings, &queued).enumerate() {
+        let label = gamepad_label(&labeler, &gamepads, event.gamepad());
+        let mut line = LogLine::new(
+            settings.name(),
+            Some(label),
+            format_payload(&plugin_settings, &settings, event),
+        )
+        .with_target(event.gamepad());
+        append_origin(&mut line, &settings, index, send_schedule(&mut sent, id));
+        emit(&plugin_settings, &settings, line);
+    }
+}
+
+/// The events sent through the [LogHandle](crate::LogHandle) of `E`.
+fn queued_events<E>(settings: &LoggedEventSettings<E>) -> impl Iterator<Item = E> + '_ {
+    settings
+        .queue
+        .iter()
+        .flat_map(|queue| queue.receiver.try_iter())
+}
+
+/// The formatter given to [log_event_with_formatter](crate::LogEvent::log_event_with_formatter).
+#[derive(Resource)]
+pub(crate) struct EventFormatter<E>(pub Box<dyn Fn(&E) -> String + Send + Sync>);
+
+pub(crate) fn log_formatted_event<E, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+    formatter: Res<EventFormatter<E>>,
+    mut events: EventReader<E>,
+    mut sent: Option<ResMut<SentSchedules<E>>>,
+) where
+    E: Event,
+{
+    let queued = queued_events(&settings);
+    if !plugin_settings.must_log(&settings) {
+        queued.for_each(drop);
+        return;
+    }
+    let queued: Vec<_> = queued.collect();
+    for (index, (event, id)) in read_with_ids(&mut events, &settings, &queued).enumerate() {
+        let mut line = LogLine::new(settings.name(), None, (formatter.0)(event));
+        append_origin(&mut line, &settings, index, send_schedule(&mut sent, id));
+        emit(&plugin_settings, &settings, line);
+    }
+}
+
+pub(crate) fn log_reflected_event<E, I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+    mut events: EventReader<E>,
+    mut sent: Option<ResMut<SentSchedules<E>>>,
+) where
+    E: Event + Reflect,
+{
+    let queued = queued_events(&settings);
+    if !plugin_settings.must_log(&settings) {
+        queued.for_each(drop);
+        return;
+    }
+    let queued: Vec<_> = queued.collect();
+    for (index, (event, id)) in read_with_ids(&mut events, &settings, &queued).enumerate() {
+        let payload = format_payload(&plugin_settings, &settings, event.as_partial_reflect());
+        let mut line = LogLine::new(settings.name(), None, payload);
+        append_origin(&mut line, &settings, index, send_schedule(&mut sent, id));
+        emit(&plugin_settings, &settings, line);
+    }
+}
+
+pub(crate) fn log_triggered<E, I: LogInstance>(
+    trigger: Trigger<E>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E>>,
+    labeler: Res<EntityLabeler>,
+    entities: Query<EntityRef>,
+    current: Option<Res<CurrentSchedule>>,
+) where
+    E: Event + std::fmt::Debug,
+{
+    if !plugin_settings.must_log(&settings) {
+        return;
+    }
+    let entity = trigger.entity();
+    if entity != Entity::PLACEHOLDER
+        && !matches_entity_filter(&plugin_settings, &settings, &entities, entity)
+    {
+        return;
+    }
+    let label = (entity != Entity::PLACEHOLDER).then(|| entity_label(&labeler, &entities, entity));
+    let mut line = LogLine::new(
+        settings.name(),
+        label,
+        format_payload(&plugin_settings, &settings, trigger.event()),
+    )
+    .with_target(entity);
+    append_trigger_schedule(&mut line, &settings, trigger_schedule(&current));
+    emit(&plugin_settings, &settings, line);
+    if entity != Entity::PLACEHOLDER {
+        mark_entity(&settings, entity);
+    }
+}
+
+/// Keep `entity` so a gizmo is drawn on it if [gizmo](EventSettings::gizmo) is enabled.
+fn mark_entity<E, C>(settings: &LoggedEventSettings<E, C>, entity: Entity) {
+    #[cfg(feature = "gizmos")]
+    if settings.gizmo {
+        settings
+            .stats
+            .lock()
+            .gizmo_entities
+            .push((entity, settings.level));
+    }
+    #[cfg(not(feature = "gizmos"))]
+    let _ = (settings, entity);
+}
+
+/// Whether the [Name] of `entity` matches the
+/// [entity_filters](LogEventsPluginSettings::entity_filters) of the event of `settings`.
+fn matches_entity_filter<E, C, I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    settings: &LoggedEventSettings<E, C>,
+    entities: &Query<EntityRef>,
+    entity: Entity,
+) -> bool {
+    let Some(filter) = plugin_settings.entity_filters.get(settings.name()) else {
+        return true;
+    };
+    let mut activity = settings.stats.lock();
+    if activity
+        .entity_filter
+        .as_ref()
+        .is_none_or(|(compiled, _)| compiled != filter)
+    {
+        activity.entity_filter = Some((filter.clone(), Regex::new(filter).ok()));
+    }
+    let Some((_, Some(regex))) = &activity.entity_filter else {
+        return true;
+    };
+    entities
+        .get(entity)
+        .ok()
+        .and_then(|entity| entity.get::<Name>())
+        .is_some_and(|name| regex.is_match(name.as_str()))
+}
+
+pub(crate) fn format_component<'a, C, I: LogInstance>(
+    plugin_settings: &LogEventsPluginSettings<I>,
+    settings: &EventSettings,
+    name: &'a str,
+    entity: Entity,
+    label: String,
+    component: Option<&C>,
+) -> Option<LogLine<'a>>
+where
+    C: Payload + ?Sized,
+{
+    let payload = match component {
+        Some(component) => format_payload(plugin_settings, settings, component),
+        None if settings.log_unavailable && entity != Entity::PLACEHOLDER => {
+            "<value unavailable>".to_string()
+        }
+        None => return None,
+    };
+    Some(LogLine::new(name, Some(label), payload).with_target(entity))
+}
+
+/// Append to the payload of `line` the ticks at which the [Component] `C` of `entity`
+/// was added and last changed, relative to `now`.
+fn append_change_ticks<C: Component>(
+    line: &mut LogLine,
+    entities: &Query<EntityRef>,
+    entity: Entity,
+    now: Tick,
+) {
+    let Some(ticks) = entities
+        .get(entity)
+        .ok()
+        .and_then(|entity| entity.get_change_ticks::<C>())
+    else {
+        return;
+    };
+    let ago = |tick: Tick| now.get().wrapping_sub(tick.get());
+    line.payload.push_str(&format!(
+        " [added={} ({} ago) changed={} ({} ago)]",
+        ticks.added.get(),
+        ago(ticks.added),
+        ticks.changed.get(),
+        ago(ticks.changed)
+    ));
+}
+
+/// Append to the payload of `line` the number of components of `entity` and the id of
+/// its archetype.
+pub(crate) fn append_entity_info(line: &mut LogLine, entities: &Query<EntityRef>, entity: Entity) {
+    let Ok(entity) = entities.get(entity) else {
+        return;
+    };
+    let archetype = entity.archetype();
+    line.payload.push_str(&format!(
+        " [components={} archetype={}]",
+        archetype.component_count(),
+        archetype.id().index()
+    ));
+}
+
+pub(crate) fn log_component<E, C, I: LogInstance>(
+    trigger: Trigger<E, C>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E, C>>,
+    labeler: Res<EntityLabeler>,
+    entities: Query<EntityRef>,
+    ticks: SystemChangeTick,
+    current: Option<Res<CurrentSchedule>>,
+) where
+    E: Event,
+    C: Component + std::fmt::Debug,
+{
+    if !plugin_settings.must_log(&settings) {
+        return;
+    }
+    let entity = trigger.entity();
+    if !matches_entity_filter(&plugin_settings, &settings, &entities, entity) {
+        return;
+    }
+    if let Some(mut line) = format_component(
+        &plugin_settings,
+        &settings,
+        settings.name(),
+        entity,
+        entity_label(&labeler, &entities, entity),
+        entities
+            .get(entity)
+            .ok()
+            .and_then(|entity| entity.get::<C>()),
+    ) {
+        if settings.change_ticks {
+            append_change_ticks::<C>(&mut line, &entities, entity, ticks.this_run());
+        }
+        if settings.entity_info {
+            append_entity_info(&mut line, &entities, entity);
+        }
+        append_trigger_schedule(&mut line, &settings, trigger_schedule(&current));
+        emit_or_gather(&plugin_settings, &settings, line);
+        mark_entity(&settings, entity);
+    }
+}
+
+/// The last value seen on each [Entity] for a [Component] logged with
+/// [log_trigger_cached](crate::LogEvent::log_trigger_cached).
+#[derive(Resource, Deref, DerefMut)]
+pub(crate) struct LastComponentValues<C>(HashMap<Entity, C>);
+
+impl<C> Default for LastComponentValues<C> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+/// The last values of the components cached by
+/// [log_trigger_cached](crate::LogEvent::log_trigger_cached), by component name then
+/// entity, the most recent last. Up to
+/// [component_history](LogEventsPluginSettings::component_history) values are kept.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct ComponentHistory(BTreeMap<&'static str, HashMap<Entity, VecDeque<String>>>);
+
+pub(crate) fn cache_component<C, I: LogInstance>(
+    trigger: Trigger<OnInsert, C>,
+    components: Query<&C>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    mut cache: ResMut<LastComponentValues<C>>,
+    mut history: ResMut<ComponentHistory>,
+) where
+    C: Component + std::fmt::Debug + Clone,
+{
+    let entity = trigger.entity();
+    let Ok(component) = components.get(entity) else {
+        return;
+    };
+    cache.insert(entity, component.clone());
+    let length = plugin_settings.component_history;
+    if length > 0 {
+        let values = history
+            .entry(type_name::<C>())
+            .or_default()
+            .entry(entity)
+            .or_default();
+        values.push_back(format!("{:?}", component));
+        while values.len() > length {
+            values.pop_front();
+        }
+    }
+}
+
+pub(crate) fn prune_component_cache<C>(
+    components: Query<(), With<C>>,
+    mut cache: ResMut<LastComponentValues<C>>,
+    mut history: ResMut<ComponentHistory>,
+) where
+    C: Component,
+{
+    cache.retain(|entity, _| components.contains(*entity));
+    if let Some(values) = history.get_mut(type_name::<C>()) {
+        values.retain(|entity, _| components.contains(*entity));
+    }
+}
+
+pub(crate) fn log_cached_component<E, C, I: LogInstance>(
+    trigger: Trigger<E, C>,
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<E, C>>,
+    cache: Res<LastComponentValues<C>>,
+    labeler: Res<EntityLabeler>,
+    entities: Query<EntityRef>,
+    (ticks, current): (SystemChangeTick, Option<Res<CurrentSchedule>>),
+) where
+    E: Event,
+    C: Component + std::fmt::Debug + Clone,
+{
+    if !plugin_settings.must_log(&settings) {
+        return;
+    }
+    let entity = trigger.entity();
+    if !matches_entity_filter(&plugin_settings, &settings, &entities, entity) {
+        return;
+    }
+    if let Some(mut line) = format_component(
+        &plugin_settings,
+        &settings,
+        settings.name(),
+        entity,
+        entity_label(&labeler, &entities, entity),
+        entities
+            .get(entity)
+            .ok()
+            .and_then(|entity| entity.get::<C>())
+            .or_else(|| cache.get(&entity)),
+    ) {
+        if settings.change_ticks {
+            append_change_ticks::<C>(&mut line, &entities, entity, ticks.this_run());
+        }
+        if settings.entity_info {
+            append_entity_info(&mut line, &entities, entity);
+        }
+        append_trigger_schedule(&mut line, &settings, trigger_schedule(&current));
+        emit_or_gather(&plugin_settings, &settings, line);
+        mark_entity(&settings, entity);
+    }
+}
+
+pub(crate) fn current_settings<I: LogInstance>(world: &World) -> LoggedEventsSettings {
+    let log_settings_ids = world.resource::<LogSettingsIds<I>>();
+    let mut all_settings = BTreeMap::new();
+    for (name, registered) in log_settings_ids.iter() {
+        if let Some(event_settings) = get_log_settings_by_id(world, &registered.id) {
+            all_settings.insert(name.clone(), event_settings.clone());
+        }
+    }
+    let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+    // A solo only lasts until it is undone, so the events are saved as they were before.
+    if let Some((_, enabled)) = &plugin_settings.solo {
+        for (name, event_settings) in all_settings.iter_mut() {
+            if let Some(enabled) = enabled.get(name) {
+                event_settings.enabled = *enabled;
+            }
+        }
+    }
+    let applied = LoggedEventsSettings {
+        plugin_enabled: plugin_settings.enabled,
+        min_level: plugin_settings.min_level,
+        channels: plugin_settings.channels.clone(),
+        templates: plugin_settings.templates.clone(),
+        entity_filters: plugin_settings.entity_filters.clone(),
+        sounds: plugin_settings.sounds.clone(),
+        output_files: plugin_settings.output_files.clone(),
+        pinned: plugin_settings.pinned.clone(),
+        favorites: plugin_settings.favorites.clone(),
+        filter_presets: plugin_settings.filter_presets.clone(),
+        watch: plugin_settings.watch.clone(),
+        renames: plugin_settings.renames.clone(),
+        window: settings_io::WindowLayout {
+            open: plugin_settings.show_window,
+            ..plugin_settings.window_layout
+        },
+        level_palette: plugin_settings.level_palette,
+        events_settings: all_settings,
+        platforms: BTreeMap::new(),
+    };
+    match &plugin_settings.file_settings {
+        Some(file_settings) => {
+            file_settings.store_platforms(&applied, settings_io::current_platforms())
+        }
+        None => applied,
+    }
+}
+
+/// Replace the settings of every registered event with the ones from `settings`, once
+/// their platform sections are applied. Events missing from `settings` are reset to their
+/// default.
+pub(crate) fn apply_settings<I: LogInstance>(
+    world: &mut World,
+    file_settings: LoggedEventsSettings,
+) {
+    apply_settings_to::<I>(world, file_settings, true);
+}
+
+/// Like [apply_settings], but the events missing from `settings` keep their settings, as
+/// they are usually events the program that wrote them did not register.
+fn apply_shared_settings<I: LogInstance>(world: &mut World, file_settings: LoggedEventsSettings) {
+    apply_settings_to::<I>(world, file_settings, false);
+}
+
+fn apply_settings_to<I: LogInstance>(
+    world: &mut World,
+    file_settings: LoggedEventsSettings,
+    reset_missing: bool,
+) {
+    let settings = file_settings.apply_platforms(settings_io::current_platforms());
+    let mut channels = settings.channels;
+    world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds<I>>| {
+        for (name, registered) in log_settings_ids.iter() {
+            let Some(event_settings) = get_log_settings_mut_by_id(world, &registered.id) else {
+                continue;
+            };
+            match settings.events_settings.get(name) {
+                Some(saved) => *event_settings = saved.clone(),
+                None if reset_missing => *event_settings = default(),
+                None => {}
+            }
+            if let Some(channel) = &registered.channel {
+                channels.entry(channel.clone()).or_default();
+            }
+        }
+    });
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    plugin_settings.enabled = settings.plugin_enabled;
+    plugin_settings.min_level = settings.min_level;
+    plugin_settings.channels = channels;
+    plugin_settings.templates = settings.templates;
+    plugin_settings.entity_filters = settings.entity_filters;
+    plugin_settings.sounds = settings.sounds;
+    plugin_settings.output_files = settings.output_files;
+    plugin_settings.pinned = settings.pinned;
+    plugin_settings.favorites = settings.favorites;
+    plugin_settings.filter_presets = settings.filter_presets;
+    plugin_settings.watch = settings.watch;
+    plugin_settings.renames = settings.renames;
+    plugin_settings.level_palette = settings.level_palette;
+    if reset_missing {
+        plugin_settings.previous_settings = settings.events_settings;
+    } else {
+        plugin_settings
+            .previous_settings
+            .extend(settings.events_settings);
+    }
+    plugin_settings.file_settings = Some(file_settings);
+    plugin_settings.solo = None;
+}
+
+/// Disable every event but `name`, remembering their [enabled](EventSettings::enabled)
+/// values. If `name` is already soloed, these values are restored instead. Soloing
+/// another event restores them before soloing it.
+pub(crate) fn toggle_solo<I: LogInstance>(world: &mut World, name: &str) {
+    let previous = world
+        .resource_mut::<LogEventsPluginSettings<I>>()
+        .solo
+        .take();
+    let solo = world.resource_scope(|world, log_settings_ids: Mut<LogSettingsIds<I>>| {
+        if let Some((soloed, enabled)) = &previous {
+            for (other, registered) in log_settings_ids.iter() {
+                if let (Some(event_settings), Some(enabled)) = (
+                    get_log_settings_mut_by_id(world, &registered.id),
+                    enabled.get(other),
+                ) {
+                    event_settings.enabled = *enabled;
+                }
+            }
+            if soloed == name {
+                return None;
+            }
+        }
+        let mut enabled = BTreeMap::new();
+        for (other, registered) in log_settings_ids.iter() {
+            if let Some(event_settings) = get_log_settings_mut_by_id(world, &registered.id) {
+                enabled.insert(other.clone(), event_settings.enabled);
+                event_settings.enabled = other == name;
+            }
+        }
+        Some((name.to_string(), enabled))
+    });
+    world.resource_mut::<LogEventsPluginSettings<I>>().solo = solo;
+}
+
+/// The event soloed from the settings window, if any.
+pub(crate) fn soloed<I: LogInstance>(plugin_settings: &LogEventsPluginSettings<I>) -> Option<&str> {
+    plugin_settings.solo.as_ref().map(|(name, _)| name.as_str())
+}
+
+#[derive(Default)]
+struct SyncState {
+    last_check: Option<Instant>,
+    modified: Option<SystemTime>,
+    synced: Option<LoggedEventsSettings>,
+}
+
+/// The weight of the last frame in the average frame time compared to the
+/// [frame_budget](LogEventsPluginSettings::frame_budget).
+const FRAME_TIME_SMOOTHING: f32 = 0.1;
+
+fn check_frame_budget<I: LogInstance>(
+    time: Option<Res<Time<Real>>>,
+    mut plugin_settings: ResMut<LogEventsPluginSettings<I>>,
+    mut average: Local<f32>,
+) {
+    let (Some(time), Some(budget)) = (time, plugin_settings.frame_budget) else {
+        if plugin_settings.over_budget {
+            plugin_settings.over_budget = false;
+        }
+        return;
+    };
+    *average += (time.delta_secs() - *average) * FRAME_TIME_SMOOTHING;
+    let over_budget = *average > budget.as_secs_f32();
+    if over_budget == plugin_settings.over_budget {
+        return;
+    }
+    plugin_settings.over_budget = over_budget;
+    if over_budget {
+        warn!(target: LOG_TARGET, "The frames take {:.1} ms on average, over the budget of {:.1} ms, the DEBUG and TRACE events are not logged until they are faster", *average * 1000., budget.as_secs_f32() * 1000.);
+    } else {
+        info!(target: LOG_TARGET, "The frames are back under the budget of {:.1} ms, the DEBUG and TRACE events are logged again", budget.as_secs_f32() * 1000.);
+    }
+}
+
+/// How often the synchronization file is checked.
+const SYNC_PERIOD: Duration = Duration::from_millis(500);
+
+fn sync_settings<I: LogInstance>(world: &mut World, mut state: Local<SyncState>) {
+    let Some(path) = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .sync_path
+        .clone()
+    else {
+        return;
+    };
+    if state
+        .last_check
+        .is_some_and(|last_check| last_check.elapsed() < SYNC_PERIOD)
+    {
+        return;
+    }
+    state.last_check = Some(Instant::now());
+    if let Err(err) = sync_with_file::<I>(world, &path, &mut state) {
+        warn!(
+            target: LOG_TARGET,
+            "Could not synchronize the settings with {:?}: {}", path, err
+        );
+    }
+}
+
+/// Write the settings in `path` if they were changed in this instance, or apply the
+/// ones from `path` if the file was changed by another instance.
+fn sync_with_file<I: LogInstance>(
+    world: &mut World,
+    path: &Path,
+    state: &mut SyncState,
+) -> Result<(), LogEventsError> {
+    let current = current_settings::<I>(world);
+    let modified = modified_time(path);
+    let changed_here = state
+        .synced
+        .as_ref()
+        .is_some_and(|synced| *synced != current);
+    if changed_here || modified.is_none() {
+        let style = world.resource::<LogEventsPluginSettings<I>>().level_style;
+        with_level_style(style, || settings_io::save(path, &current))?;
+        state.modified = modified_time(path);
+        state.synced = Some(current);
+    } else if modified != state.modified {
+        let loaded = settings_io::load(path)?;
+        if loaded != current {
+            apply_shared_settings::<I>(world, loaded);
+        }
+        state.modified = modified;
+        state.synced = Some(current_settings::<I>(world));
+    }
+    Ok(())
+}
+
+/// The settings file as it was when it was last read by [hot_reload_settings].
+#[derive(Default)]
+struct HotReloadState {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    loaded: Option<LoggedEventsSettings>,
+}
+
+/// Apply the entries changed in the settings file since it was last read, see
+/// [with_hot_reload](LogEventsPlugin::with_hot_reload).
+fn hot_reload_settings<I: LogInstance>(world: &mut World, mut state: Local<HotReloadState>) {
+    let path = world
+        .resource::<LogEventsPluginSettings<I>>()
+        .saved_settings
+        .clone();
+    let modified = modified_time(&path);
+    if path != state.path {
+        // The first check, or another file was loaded from the settings window.
+        state.loaded = world
+            .resource::<LogEventsPluginSettings<I>>()
+            .read_file(&path)
+            .ok();
+        state.path = path;
+        state.modified = modified;
+        return;
+    }
+    if modified.is_none() || modified == state.modified {
+        return;
+    }
+    state.modified = modified;
+    let loaded = match world
+        .resource::<LogEventsPluginSettings<I>>()
+        .read_file(&path)
+    {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            warn!(target: LOG_TARGET, "Could not reload the settings from {:?}: {}", path, err);
+            return;
+        }
+    };
+    let current = current_settings::<I>(world);
+    let reloaded = match &state.loaded {
+        Some(previous) => settings_io::merge(previous, &loaded, &current),
+        None => loaded.clone(),
+    };
+    if reloaded != current {
+        apply_settings::<I>(world, reloaded);
+        info!(target: LOG_TARGET, "Reloaded the settings from {:?}", path);
+    }
+    state.loaded = Some(loaded);
+}
+
+/// Read the settings file at `path` written in `format`, as an overlay of `base` if there
+/// is one.
+fn read_file(
+    path: &Path,
+    format: SettingsFormat,
+    base: Option<&LoggedEventsSettings>,
+) -> Result<LoggedEventsSettings, LogEventsError> {
+    match base {
+        Some(base) => Ok(base.apply_overlay(&settings_io::load_overlay_as(path, format)?)),
+        None => settings_io::load_as(path, format),
+    }
+}
+
+/// Write `settings` in `format` in the file at `path` with their levels in `style`, keeping
+/// only their differences with `base` if there is one.
+fn write_file(
+    path: &Path,
+    format: SettingsFormat,
+    style: LevelStyle,
+    base: Option<&LoggedEventsSettings>,
+    settings: &LoggedEventsSettings,
+) -> Result<(), LogEventsError> {
+    with_level_style(style, || match base {
+        Some(base) => settings_io::save_overlay_as(path, &settings.store_overlay(base), format),
+        None => settings_io::save_as(path, settings, format),
+    })
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+const PANIC_SNAPSHOT_PERIOD: Duration = Duration::from_secs(1);
+
+/// The settings to save if the program panics.
+#[derive(Resource, Clone)]
+struct PanicSnapshot<I>(Arc<Mutex<Option<SaveRequest>>>, PhantomData<fn() -> I>);
+
+fn install_panic_hook<I: LogInstance>(app: &mut App) {
+    let snapshot = PanicSnapshot::<I>(default(), PhantomData);
+    let hook_snapshot = snapshot.0.clone();
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // try_lock as the panic could happen while the snapshot is updated.
+        if let Ok(Some(request)) = hook_snapshot.try_lock().as_deref() {
+            let saved = request.prepare();
+            if let Err(e) = request.write(&request.path, request.format, &saved) {
+                eprintln!(
+                    "bevy_log_events: could not save the settings at {:?}: {}",
+                    request.path, e
+                );
+            }
+        }
+        previous_hook(info);
+    }));
+    app.insert_resource(snapshot)
+        .add_systems(Last, update_panic_snapshot::<I>);
+}
+
+fn update_panic_snapshot<I: LogInstance>(world: &World, mut last_update: Local<Option<Instant>>) {
+    if last_update.is_some_and(|last_update| last_update.elapsed() < PANIC_SNAPSHOT_PERIOD) {
+        return;
+    }
+    *last_update = Some(Instant::now());
+    let request = SaveRequest::new::<I>(world);
+    let snapshot = world.resource::<PanicSnapshot<I>>();
+    *snapshot.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(request);
+}
+
+/// Whether the file at `path` exists and was modified after the one at `other`.
+fn is_newer(path: &Path, other: &Path) -> bool {
+    let modified = |path: &Path| {
+        path.metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    };
+    match (modified(path), modified(other)) {
+        (Some(modified), Some(other)) => modified > other,
+        (modified, _) => modified.is_some(),
+    }
+}
+
+/// The current settings with what is needed to write them in the settings file, following
+/// the [SavePolicy] and the [base](LogEventsPlugin::with_base) of the plugin.
+struct SaveRequest {
+    path: PathBuf,
+    format: SettingsFormat,
+    level_style: LevelStyle,
+    current: LoggedEventsSettings,
+    save_policy: SavePolicy,
+    save_base: Option<LoggedEventsSettings>,
+    base_settings: Option<LoggedEventsSettings>,
+}
+
+impl SaveRequest {
+    fn new<I: LogInstance>(world: &World) -> Self {
+        let plugin_settings = world.resource::<LogEventsPluginSettings<I>>();
+        let path = plugin_settings.saved_settings.clone();
+        Self {
+            format: plugin_settings.format_of(&path),
+            path,
+            level_style: plugin_settings.level_style,
+            current: current_settings::<I>(world),
+            save_policy: plugin_settings.save_policy,
+            save_base: plugin_settings.save_base.clone(),
+            base_settings: plugin_settings.base_settings.clone(),
+        }
+    }
+
+    /// The settings to save: the current settings, merged with the file with
+    /// [SavePolicy::Merge].
+    fn prepare(&self) -> LoggedEventsSettings {
+        let mut saved = self.current.clone();
+        if self.save_policy == SavePolicy::Merge {
+            if let (Some(save_base), Ok(theirs)) = (
+                &self.save_base,
+                read_file(&self.path, self.format, self.base_settings.as_ref()),
+            ) {
+                saved = settings_io::merge(save_base, &saved, &theirs);
+            }
+        }
+        saved
+    }
+
+    /// Write `saved` in the file at `path`, as an overlay of the base if there is one.
+    fn write(
+        &self,
+        path: &Path,
+        format: SettingsFormat,
+        saved: &LoggedEventsSettings,
+    ) -> Result<(), LogEventsError> {
+        write_file(
+            path,
+            format,
+            self.level_style,
+            self.base_settings.as_ref(),
+            saved,
+        )
+    }
+}
+
+fn save_settings<I: LogInstance>(world: &mut World) {
+    let request = SaveRequest::new::<I>(world);
+    let saved = request.prepare();
+    let path = request.path.clone();
+    let result = request.write(&path, request.format, &saved);
+    let mut plugin_settings = world.resource_mut::<LogEventsPluginSettings<I>>();
+    plugin_settings.save_base = Some(saved.clone());
+    plugin_settings.file_settings = Some(saved.clone());
+    let Err(e) = result else {
+        return;
+    };
+    if let Some(fallback_path) = plugin_settings
+        .fallback_path
+        .clone()
+        .filter(|fallback_path| *fallback_path != path)
+    {
+        let fallback_format = plugin_settings.format_of(&fallback_path);
+        if request
+            .write(&fallback_path, fallback_format, &saved)
+            .is_ok()
+        {
+            warn!(
+                target: LOG_TARGET,
+                "Could not save the settings at {:?} due to {}, they were saved at {:?} instead",
+                path,
+                e,
+                fallback_path
+            );
+            plugin_settings.saved_settings = fallback_path;
+            return;
+        }
+    }
+    error!(
+        target: LOG_TARGET,
+        "Could not save {} at {:?} due to {:?}",
+        type_name::<LoggedEventsSettings>(),
+        path,
+        e
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogEvent;
+
+    #[derive(Event, Debug)]
+    struct Shared;
+
+    #[derive(Event, Debug)]
+    struct Local;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        let path = std::env::temp_dir().join("bevy_log_events_tests/missing.ron");
+        app.add_plugins((MinimalPlugins, LogEventsPlugin::new(path)))
+            .add_and_log_event::<Shared>()
+            .add_and_log_event::<Local>();
+        app.finish();
+        app.update();
+        app
+    }
+
+    /// The settings with only the ones of [Shared], disabled.
+    fn shared_settings(world: &World) -> LoggedEventsSettings {
+        let mut settings = current_settings::<()>(world);
+        settings
+            .events_settings
+            .retain(|name, _| name == type_name::<Shared>());
+        for event_settings in settings.events_settings.values_mut() {
+            event_settings.enabled = false;
+        }
+        settings
+    }
+
+    #[test]
+    fn shared_settings_keep_missing_events() {
+        let mut app = test_app();
+        let world = app.world_mut();
+        world.resource_mut::<LoggedEventSettings<Local>>().level = Level::ERROR;
+        let settings = shared_settings(world);
+        apply_shared_settings::<()>(world, settings);
+        assert!(!world.resource::<LoggedEventSettings<Shared>>().enabled);
+        assert_eq!(
+            world.resource::<LoggedEventSettings<Local>>().level,
+            Level::ERROR
+        );
+    }
+
+    #[test]
+    fn applied_settings_reset_missing_events() {
+        let mut app = test_app();
+        let world = app.world_mut();
+        world.resource_mut::<LoggedEventSettings<Local>>().level = Level::ERROR;
+        let settings = shared_settings(world);
+        apply_settings::<()>(world, settings);
+        assert!(!world.resource::<LoggedEventSettings<Shared>>().enabled);
+        assert_eq!(
+            **world.resource::<LoggedEventSettings<Local>>(),
+            EventSettings::default()
         );
     }
 }
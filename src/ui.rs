@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use crate::{
+    systems::{
+        add_to_log_schedule, emit, entity_label, queue_registration, register_settings,
+        InstanceSet, LogLine,
+    },
+    EntityLabeler, LogEventsPluginSettings, LogInstance, LoggedEventSettings,
+};
+
+/// Marker used for the [LoggedEventSettings] of the [Interaction] changes.
+pub(crate) struct InteractionChanged;
+
+/// Marker used for the [LoggedEventSettings] of the [Button] presses.
+pub(crate) struct ButtonPressed;
+
+const INTERACTION_CHANGED: &str = "Interaction changed";
+const BUTTON_PRESSED: &str = "Button pressed";
+
+pub(crate) fn plugin<I: LogInstance>(app: &mut App) {
+    if app
+        .world()
+        .contains_resource::<LoggedEventSettings<InteractionChanged, Interaction>>()
+    {
+        warn!("You tried to use log_ui_interactions twice");
+        return;
+    }
+    let interaction_settings =
+        crate::new_logged_settings::<InteractionChanged, Interaction>(app.world());
+    let button_settings = crate::new_logged_settings::<ButtonPressed, Button>(app.world());
+    queue_registration::<I>(app, INTERACTION_CHANGED, |world| {
+        register_settings::<InteractionChanged, Interaction, I>(
+            world,
+            INTERACTION_CHANGED.to_string(),
+        )
+    });
+    queue_registration::<I>(app, BUTTON_PRESSED, |world| {
+        register_settings::<ButtonPressed, Button, I>(world, BUTTON_PRESSED.to_string())
+    });
+    app.insert_resource(interaction_settings)
+        .insert_resource(button_settings);
+    add_to_log_schedule::<I, _>(
+        app,
+        (log_interactions::<I>, log_button_presses::<I>).in_set(InstanceSet::of::<I>()),
+    );
+}
+
+fn log_interactions<I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<InteractionChanged, Interaction>>,
+    labeler: Res<EntityLabeler>,
+    entities: Query<EntityRef>,
+    interactions: Query<(Entity, Ref<Interaction>), Changed<Interaction>>,
+) {
+    if !plugin_settings.must_log(&settings) {
+        return;
+    }
+    for (entity, interaction) in &interactions {
+        if interaction.is_added() {
+            continue;
+        }
+        let line = LogLine {
+            name: INTERACTION_CHANGED,
+            entity: Some(entity_label(&labeler, &entities, entity)),
+            payload: format!("{:?}", *interaction),
+            default_template: "{name} to {payload} on {entity}",
+            emitted: None,
+            target: Some(entity),
+        };
+        emit(&plugin_settings, &settings, line);
+    }
+}
+
+fn log_button_presses<I: LogInstance>(
+    plugin_settings: Res<LogEventsPluginSettings<I>>,
+    settings: Res<LoggedEventSettings<ButtonPressed, Button>>,
+    labeler: Res<EntityLabeler>,
+    entities: Query<EntityRef>,
+    buttons: Query<(Entity, Ref<Interaction>), With<Button>>,
+) {
+    if !plugin_settings.must_log(&settings) {
+        return;
+    }
+    for (entity, interaction) in &buttons {
+        if interaction.is_changed() && *interaction == Interaction::Pressed {
+            let line = LogLine {
+                name: BUTTON_PRESSED,
+                entity: Some(entity_label(&labeler, &entities, entity)),
+                payload: String::new(),
+                default_template: "{name} on {entity}",
+                emitted: None,
+                target: Some(entity),
+            };
+            emit(&plugin_settings, &settings, line);
+        }
+    }
+}
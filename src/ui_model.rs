@@ -0,0 +1,320 @@
+//! The registered events and their settings as plain data, to build a settings UI with
+//! another library than egui without depending on the internals of the plugin.
+//!
+//! An adapter typically shows the [rows] matching the filters of the user, then calls
+//! [set_event_settings] when one of them is edited. The plugin wide settings are changed
+//! directly on the [LogEventsPluginSettings] resource. The [dev_tools](crate::dev_tools)
+//! commands are built this way and can serve as a reference.
+//!
+//! The events can also be filtered with a [FilterExpr], the same expressions that can be
+//! typed in the search box of the settings window.
+//!
+//! As an example :
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_log_events::{prelude::*, ui_model, LogEventsError};
+//! fn disable_netcode_events(world: &mut World) -> Result<(), LogEventsError> {
+//!     let filter = FilterPreset {
+//!         name_filter: "netcode".into(),
+//!         ..default()
+//!     };
+//!     for row in ui_model::rows(world, &filter) {
+//!         let mut settings = row.settings;
+//!         settings.enabled = false;
+//!         ui_model::set_event_settings(world, &row.name, settings)?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use bevy::{log::Level, prelude::*};
+
+use regex::Regex;
+
+use crate::{
+    systems::LogSettingsIds,
+    utils::{
+        get_log_settings_by_id, get_log_settings_mut_by_id, parse_filter, FilterNode, FilterSubject,
+    },
+    EventSettings, FilterPreset, LogEventsError, LogEventsPluginSettings,
+};
+
+/// A registered event, as shown in a row of the settings window.
+#[derive(Clone, PartialEq, Debug)]
+pub struct EventRow {
+    /// The name of the event, used to change its settings.
+    pub name: String,
+    /// The log channel of the event, if any.
+    pub channel: Option<String>,
+    /// The group of the event, if any.
+    pub group: Option<&'static str>,
+    /// The settings of the event.
+    pub settings: EventSettings,
+    /// Whether the event is logged, taking the settings of the plugin, of its channel
+    /// and of its group into account.
+    pub logged: bool,
+    /// The number of logs made since the start of the run.
+    pub log_count: u64,
+    /// The number of logs not made because of the settings limiting them.
+    pub suppressed: u64,
+}
+
+/// The registered events matching `filter`, sorted by name. Use
+/// [FilterPreset::default] to get all of them.
+pub fn rows(world: &World, filter: &FilterPreset) -> Vec<EventRow> {
+    let plugin_settings = world.resource::<LogEventsPluginSettings>();
+    let event_filter = EventFilter::new(filter);
+    world
+        .resource::<LogSettingsIds>()
+        .iter()
+        .map(|(name, registered)| {
+            let settings = get_log_settings_by_id(world, &registered.id)
+                .cloned()
+                .unwrap_or_default();
+            let activity = registered.stats.lock();
+            let logged = plugin_settings.must_log_event(
+                &settings,
+                registered.channel.as_deref(),
+                registered.group,
+            );
+            EventRow {
+                name: name.clone(),
+                channel: registered.channel.clone(),
+                group: registered.group,
+                settings,
+                logged,
+                log_count: activity.logged,
+                suppressed: activity.suppressed,
+            }
+        })
+        .filter(|row| {
+            event_filter.matches(&row.name, &row.settings, row.channel.as_deref(), row.group)
+        })
+        .collect()
+}
+
+/// The settings of the event `name`.
+pub fn event_settings(world: &World, name: &str) -> Result<EventSettings, LogEventsError> {
+    let id = world
+        .resource::<LogSettingsIds>()
+        .get(name)
+        .map(|registered| registered.id)
+        .ok_or_else(|| LogEventsError::UnknownEvent(name.to_string()))?;
+    get_log_settings_by_id(world, &id)
+        .cloned()
+        .ok_or_else(|| LogEventsError::UnknownEvent(name.to_string()))
+}
+
+/// Replace the settings of the event `name` with `settings`.
+pub fn set_event_settings(
+    world: &mut World,
+    name: &str,
+    settings: EventSettings,
+) -> Result<(), LogEventsError> {
+    let id = world
+        .resource::<LogSettingsIds>()
+        .get(name)
+        .map(|registered| registered.id)
+        .ok_or_else(|| LogEventsError::UnknownEvent(name.to_string()))?;
+    *get_log_settings_mut_by_id(world, &id)
+        .ok_or_else(|| LogEventsError::UnknownEvent(name.to_string()))? = settings;
+    Ok(())
+}
+
+/// A filter expression over the registered events, like
+/// `name~"combat" && level>=DEBUG && enabled`.
+///
+/// The expressions are made of :
+/// - `enabled`, true for the enabled events.
+/// - `name~"text"`, true when the name contains `text` whatever the case. A text alone,
+///   like `"text"` or `text`, is the same.
+/// - `name="name"`, true for the event named `name`.
+/// - `level>=DEBUG`, comparing the level of the event with `=`, `!=`, `<`, `<=`, `>` or
+///   `>=`. As in [tracing](bevy::log::tracing), the more verbose levels are the greater.
+/// - `channel="name"` and `group="name"`, true for the events of this channel or group.
+/// - `!`, `&&` and `||` to combine them, and parentheses to group them.
+///
+/// The quotes can be left out for the texts without spaces or symbols.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FilterExpr(FilterNode);
+
+impl FilterExpr {
+    /// Parse `expression`, returning a [LogEventsError::InvalidFilter] telling what is wrong
+    /// with it if it is not valid.
+    pub fn parse(expression: &str) -> Result<Self, LogEventsError> {
+        parse_filter(expression)
+            .map(Self)
+            .map_err(LogEventsError::InvalidFilter)
+    }
+
+    /// Whether the event of `row` matches the expression.
+    pub fn matches(&self, row: &EventRow) -> bool {
+        self.0.matches(&FilterSubject {
+            name: &row.name,
+            settings: &row.settings,
+            channel: row.channel.as_deref(),
+            group: row.group,
+        })
+    }
+}
+
+/// A [FilterPreset] ready to be matched against many events, its regular expression or
+/// its [FilterExpr] being only parsed once. An invalid regular expression or filter
+/// expression matches no event.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    filter: String,
+    case_sensitive: bool,
+    regex: Option<Option<Regex>>,
+    expression: Option<Result<FilterNode, String>>,
+    enabled: Option<bool>,
+    level: Option<Level>,
+}
+
+impl EventFilter {
+    /// Compile the filters of `preset`.
+    pub fn new(preset: &FilterPreset) -> Self {
+        let filter = if preset.case_sensitive {
+            preset.name_filter.clone()
+        } else {
+            preset.name_filter.to_lowercase()
+        };
+        let use_expression = preset.use_expression && !preset.name_filter.trim().is_empty();
+        Self {
+            regex: preset.use_regex.then(|| Regex::new(&filter).ok()),
+            expression: use_expression.then(|| parse_filter(&preset.name_filter)),
+            filter,
+            case_sensitive: preset.case_sensitive,
+            enabled: preset.enabled,
+            level: preset.level,
+        }
+    }
+
+    /// Whether the event `name` with these `settings`, in `channel` and `group`, matches
+    /// the filter.
+    pub fn matches(
+        &self,
+        name: &str,
+        settings: &EventSettings,
+        channel: Option<&str>,
+        group: Option<&str>,
+    ) -> bool {
+        let search = match &self.expression {
+            Some(expression) => expression.as_ref().is_ok_and(|node| {
+                node.matches(&FilterSubject {
+                    name,
+                    settings,
+                    channel,
+                    group,
+                })
+            }),
+            None => self.matches_name(name),
+        };
+        search
+            && self
+                .enabled
+                .is_none_or(|enabled| settings.enabled == enabled)
+            && self.level.is_none_or(|level| settings.level == level)
+    }
+
+    /// Why the filter expression is not valid, if it is not.
+    pub fn error(&self) -> Option<&str> {
+        self.expression.as_ref()?.as_ref().err().map(String::as_str)
+    }
+
+    fn matches_name(&self, name: &str) -> bool {
+        let name = if self.case_sensitive {
+            name.to_string()
+        } else {
+            name.to_lowercase()
+        };
+        match &self.regex {
+            Some(regex) => regex.as_ref().is_some_and(|regex| regex.is_match(&name)),
+            None => name.contains(&self.filter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(preset: FilterPreset, name: &str, settings: &EventSettings) -> bool {
+        EventFilter::new(&preset).matches(name, settings, Some("combat"), None)
+    }
+
+    #[test]
+    fn name_filter() {
+        let settings = EventSettings::default();
+        let preset = |name_filter: &str, case_sensitive| FilterPreset {
+            name_filter: name_filter.into(),
+            case_sensitive,
+            ..default()
+        };
+        assert!(matches(preset("", false), "PlayerMoved", &settings));
+        assert!(matches(preset("player", false), "PlayerMoved", &settings));
+        assert!(!matches(preset("player", true), "PlayerMoved", &settings));
+        assert!(matches(preset("Player", true), "PlayerMoved", &settings));
+        assert!(!matches(preset("enemy", false), "PlayerMoved", &settings));
+    }
+
+    #[test]
+    fn regex_filter() {
+        let settings = EventSettings::default();
+        let preset = |name_filter: &str| FilterPreset {
+            name_filter: name_filter.into(),
+            use_regex: true,
+            ..default()
+        };
+        assert!(matches(preset("^player.*d$"), "PlayerMoved", &settings));
+        assert!(!matches(preset("^moved"), "PlayerMoved", &settings));
+        // An invalid regular expression matches no event.
+        assert!(!matches(preset("(player"), "PlayerMoved", &settings));
+    }
+
+    #[test]
+    fn expression_filter() {
+        let settings = EventSettings {
+            enabled: true,
+            level: Level::DEBUG,
+            ..default()
+        };
+        let preset = |name_filter: &str| FilterPreset {
+            name_filter: name_filter.into(),
+            use_expression: true,
+            ..default()
+        };
+        let expression = "name~\"player\" && level>=DEBUG && channel=combat";
+        assert!(matches(preset(expression), "PlayerMoved", &settings));
+        assert!(!matches(preset("!enabled"), "PlayerMoved", &settings));
+        let invalid = EventFilter::new(&preset("enabled &&"));
+        assert!(invalid.error().is_some());
+        assert!(!invalid.matches("PlayerMoved", &settings, None, None));
+    }
+
+    #[test]
+    fn enabled_and_level_filters() {
+        let settings = EventSettings {
+            enabled: false,
+            level: Level::WARN,
+            ..default()
+        };
+        let preset = |enabled, level| FilterPreset {
+            enabled,
+            level,
+            ..default()
+        };
+        assert!(matches(preset(Some(false), None), "PlayerMoved", &settings));
+        assert!(!matches(preset(Some(true), None), "PlayerMoved", &settings));
+        assert!(matches(
+            preset(None, Some(Level::WARN)),
+            "PlayerMoved",
+            &settings
+        ));
+        assert!(!matches(
+            preset(None, Some(Level::INFO)),
+            "PlayerMoved",
+            &settings
+        ));
+    }
+}
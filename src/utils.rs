@@ -1,59 +1,620 @@
-use std::{any::type_name, collections::BTreeMap};
+use std::{
+    any::{type_name, TypeId},
+    cell::Cell,
+};
 
 use bevy::{ecs::component::ComponentId, log::Level, prelude::*};
 
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
-use crate::EventSettings;
+use crate::{EventSettings, LevelStyle, LogFormat, LoggedEventSettings};
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct LoggedEventsSettings {
-    pub plugin_enabled: bool,
-    pub events_settings: BTreeMap<String, EventSettings>,
+thread_local! {
+    /// The [LevelStyle] of the levels serialized on this thread, see [with_level_style].
+    static LEVEL_STYLE: Cell<LevelStyle> = const { Cell::new(LevelStyle::Uppercase) };
+}
+
+/// Run `f` with the levels it serializes written in `style`. The style is only set on the
+/// current thread and restored afterwards, so the plugins of several [App] do not write
+/// their files in the style of each other.
+pub(crate) fn with_level_style<T>(style: LevelStyle, f: impl FnOnce() -> T) -> T {
+    struct Restore(LevelStyle);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LEVEL_STYLE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(LEVEL_STYLE.replace(style));
+    f()
+}
+
+/// A [Level] written in the style given to [with_level_style] and read from any of the
+/// [LevelStyle].
+struct SerdeLevel(Level);
+
+impl Serialize for SerdeLevel {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match LEVEL_STYLE.get() {
+            LevelStyle::Uppercase => s.serialize_str(self.0.as_str()),
+            LevelStyle::Lowercase => s.serialize_str(&self.0.as_str().to_lowercase()),
+            LevelStyle::Numeric => s.serialize_u8(level_number(self.0)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeLevel {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct LevelVisitor;
+
+        impl Visitor<'_> for LevelVisitor {
+            type Value = Level;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a log level name or a number from 1 to 5")
+            }
+
+            fn visit_str<E: Error>(self, s: &str) -> Result<Level, E> {
+                s.parse().map_err(|_| {
+                    E::custom(format!("\"{}\" does not represent a valid log Level", s))
+                })
+            }
+
+            fn visit_u64<E: Error>(self, n: u64) -> Result<Level, E> {
+                LEVELS
+                    .into_iter()
+                    .find(|level| u64::from(level_number(*level)) == n)
+                    .ok_or_else(|| E::custom(format!("{} does not represent a valid log Level", n)))
+            }
+
+            fn visit_i64<E: Error>(self, n: i64) -> Result<Level, E> {
+                let n = u64::try_from(n).map_err(|_| {
+                    E::custom(format!("{} does not represent a valid log Level", n))
+                })?;
+                self.visit_u64(n)
+            }
+        }
+
+        d.deserialize_any(LevelVisitor).map(SerdeLevel)
+    }
+}
+
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// The number of `level` in [LevelStyle::Numeric], from 1 for `ERROR` to 5 for `TRACE`.
+fn level_number(level: Level) -> u8 {
+    LEVELS.iter().position(|l| *l == level).unwrap() as u8 + 1
 }
 
 pub(crate) fn serialize_level<S>(level: &Level, s: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    s.serialize_str(level.as_str())
+    SerdeLevel(*level).serialize(s)
 }
 
+/// Read a [Level] written in any [LevelStyle], whatever the case of its name.
 pub(crate) fn deserialize_level<'de, D>(d: D) -> Result<Level, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s: &str = Deserialize::deserialize(d)?;
-    match s {
-        "ERROR" => Ok(Level::ERROR),
-        "WARN" => Ok(Level::WARN),
-        "INFO" => Ok(Level::INFO),
-        "DEBUG" => Ok(Level::DEBUG),
-        "TRACE" => Ok(Level::TRACE),
-        _ => Err(D::Error::custom(format!(
-            "\"{}\" does not represent a valid log Level",
-            s
-        ))),
+    SerdeLevel::deserialize(d).map(|level| level.0)
+}
+
+pub(crate) fn serialize_optional_level<S>(level: &Option<Level>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match level {
+        Some(level) => s.serialize_some(&SerdeLevel(*level)),
+        None => s.serialize_none(),
+    }
+}
+
+pub(crate) fn deserialize_optional_level<'de, D>(d: D) -> Result<Option<Level>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let level: Option<SerdeLevel> = Deserialize::deserialize(d)?;
+    Ok(level.map(|level| level.0))
+}
+
+pub(crate) fn serialize_format<S>(format: &LogFormat, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_str(&format.to_string())
+}
+
+/// Read a [LogFormat], or the `pretty` boolean used before it existed.
+pub(crate) fn deserialize_format<'de, D>(d: D) -> Result<LogFormat, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct FormatVisitor;
+
+    impl Visitor<'_> for FormatVisitor {
+        type Value = LogFormat;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "a log format name or a boolean")
+        }
+
+        fn visit_bool<E: Error>(self, pretty: bool) -> Result<LogFormat, E> {
+            Ok(if pretty {
+                LogFormat::Pretty
+            } else {
+                LogFormat::Compact
+            })
+        }
+
+        fn visit_str<E: Error>(self, s: &str) -> Result<LogFormat, E> {
+            Ok(LogFormat::from_name(s))
+        }
     }
+
+    d.deserialize_any(FormatVisitor)
 }
 
 fn type_stem<'a, T>() -> &'a str {
     type_name::<T>().split("::").last().unwrap()
 }
 
+/// Strip the module paths from a type name, `a::B<c::D>` becoming `B<D>`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    map_paths(name, |path| path.rsplit("::").next().unwrap().to_string())
+}
+
+/// Shorten the modules of a type name to their first letter, `ab::cd::E<fg::H>` becoming
+/// `a::c::E<f::H>`.
+pub(crate) fn abbreviated_type_name(name: &str) -> String {
+    map_paths(name, |path| {
+        let (modules, stem) = path.rsplit_once("::").unwrap_or(("", path));
+        let mut abbreviated = String::new();
+        for module in modules.split("::").filter(|module| !module.is_empty()) {
+            abbreviated.extend(module.chars().next());
+            abbreviated.push_str("::");
+        }
+        abbreviated.push_str(stem);
+        abbreviated
+    })
+}
+
+/// Replace each path of a type name with `f(path)`, keeping the generic parameters,
+/// tuples and references around them as they are.
+fn map_paths(name: &str, f: impl Fn(&str) -> String) -> String {
+    let mut mapped = String::with_capacity(name.len());
+    let mut start = 0;
+    let is_path = |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+    for (i, c) in name.char_indices().filter(|(_, c)| !is_path(*c)) {
+        mapped.push_str(&f(&name[start..i]));
+        mapped.push(c);
+        start = i + c.len_utf8();
+    }
+    mapped.push_str(&f(&name[start..]));
+    mapped
+}
+
 pub(crate) fn trigger_name<E, C>() -> String {
     format!("{}<{}>", type_stem::<E>(), type_name::<C>())
 }
 
-pub(crate) fn get_log_settings_by_id<'a>(world: &'a World, id: &ComponentId) -> &'a EventSettings {
-    let ptr = world.get_resource_by_id(*id).unwrap();
-    unsafe { ptr.deref::<EventSettings>() }
+/// The id of a [LoggedEventSettings] resource, with the type it was created for so it can
+/// be checked before the resource is accessed through its [EventSettings].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct SettingsId {
+    component: ComponentId,
+    type_id: TypeId,
+}
+
+impl SettingsId {
+    /// The id of the [LoggedEventSettings] of `E` and `C`, if it is registered in `world`.
+    pub(crate) fn of<E, C>(world: &World) -> Option<Self>
+    where
+        E: Send + Sync + 'static,
+        C: Send + Sync + 'static,
+    {
+        Some(Self {
+            component: world
+                .components()
+                .resource_id::<LoggedEventSettings<E, C>>()?,
+            type_id: TypeId::of::<LoggedEventSettings<E, C>>(),
+        })
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        self.component.index()
+    }
+
+    /// Whether `id` still designates the resource it was created for in `world`, which
+    /// would not be the case with an id coming from another [World].
+    fn is_valid(&self, world: &World) -> bool {
+        world
+            .components()
+            .get_info(self.component)
+            .and_then(|info| info.type_id())
+            == Some(self.type_id)
+    }
+}
+
+/// The [EventSettings] of the [LoggedEventSettings] designated by `id`, if it exists.
+pub(crate) fn get_log_settings_by_id<'a>(
+    world: &'a World,
+    id: &SettingsId,
+) -> Option<&'a EventSettings> {
+    if !id.is_valid(world) {
+        return None;
+    }
+    let ptr = world.get_resource_by_id(id.component)?;
+    // SAFETY: The resource is a `LoggedEventSettings`, which is `repr(C)` with its
+    // `EventSettings` as first field.
+    Some(unsafe { ptr.deref::<EventSettings>() })
 }
 
+/// Like [get_log_settings_by_id] but mutably.
 pub(crate) fn get_log_settings_mut_by_id<'a>(
     world: &'a mut World,
-    id: &ComponentId,
-) -> &'a mut EventSettings {
-    let mut_ptr = world.get_resource_mut_by_id(*id).unwrap();
-    unsafe { mut_ptr.into_inner().deref_mut::<EventSettings>() }
+    id: &SettingsId,
+) -> Option<&'a mut EventSettings> {
+    if !id.is_valid(world) {
+        return None;
+    }
+    let mut_ptr = world.get_resource_mut_by_id(id.component)?;
+    // SAFETY: Same as in `get_log_settings_by_id`.
+    Some(unsafe { mut_ptr.into_inner().deref_mut::<EventSettings>() })
+}
+
+/// A node of a filter expression, see [FilterExpr](crate::ui_model::FilterExpr).
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum FilterNode {
+    And(Box<FilterNode>, Box<FilterNode>),
+    Or(Box<FilterNode>, Box<FilterNode>),
+    Not(Box<FilterNode>),
+    Enabled,
+    /// Stored in lowercase as the search ignores the case.
+    NameContains(String),
+    NameIs(String),
+    Level(Comparison, Level),
+    Channel(String),
+    Group(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn holds<T: PartialOrd>(self, left: T, right: T) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+/// What a [FilterNode] is matched against.
+pub(crate) struct FilterSubject<'a> {
+    pub name: &'a str,
+    pub settings: &'a EventSettings,
+    pub channel: Option<&'a str>,
+    pub group: Option<&'a str>,
+}
+
+impl FilterNode {
+    pub(crate) fn matches(&self, subject: &FilterSubject) -> bool {
+        match self {
+            FilterNode::And(left, right) => left.matches(subject) && right.matches(subject),
+            FilterNode::Or(left, right) => left.matches(subject) || right.matches(subject),
+            FilterNode::Not(node) => !node.matches(subject),
+            FilterNode::Enabled => subject.settings.enabled,
+            FilterNode::NameContains(text) => subject.name.to_lowercase().contains(text),
+            FilterNode::NameIs(name) => subject.name == name,
+            FilterNode::Level(comparison, level) => {
+                comparison.holds(subject.settings.level, *level)
+            }
+            FilterNode::Channel(channel) => subject.channel == Some(channel),
+            FilterNode::Group(group) => subject.group == Some(group),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Word(String),
+    Text(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 13] = [
+    "&&", "||", "==", "!=", "<=", ">=", "!", "(", ")", "~", "=", "<", ">",
+];
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+    while let Some(first) = rest.chars().next() {
+        if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else if first == '"' {
+            let end = rest[1..]
+                .find('"')
+                .ok_or_else(|| "a quoted text is not closed".to_string())?;
+            tokens.push(Token::Text(rest[1..end + 1].to_string()));
+            rest = &rest[end + 2..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '"' || "&|=!<>()~".contains(c))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                // A symbol character that does not start any of the SYMBOLS, like a lone `&`.
+                return Err(format!("unexpected \"{}\"", first));
+            }
+            tokens.push(Token::Word(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+struct FilterParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl FilterParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn or(&mut self) -> Result<FilterNode, String> {
+        let mut node = self.and()?;
+        while self.eat("||") {
+            node = FilterNode::Or(Box::new(node), Box::new(self.and()?));
+        }
+        Ok(node)
+    }
+
+    fn and(&mut self) -> Result<FilterNode, String> {
+        let mut node = self.not()?;
+        while self.eat("&&") {
+            node = FilterNode::And(Box::new(node), Box::new(self.not()?));
+        }
+        Ok(node)
+    }
+
+    fn not(&mut self) -> Result<FilterNode, String> {
+        if self.eat("!") {
+            Ok(FilterNode::Not(Box::new(self.not()?)))
+        } else {
+            self.atom()
+        }
+    }
+
+    fn atom(&mut self) -> Result<FilterNode, String> {
+        if self.eat("(") {
+            let node = self.or()?;
+            if !self.eat(")") {
+                return Err("a parenthesis is not closed".to_string());
+            }
+            return Ok(node);
+        }
+        match self.next() {
+            Some(Token::Text(text)) => Ok(FilterNode::NameContains(text.to_lowercase())),
+            Some(Token::Word(word)) => match word.as_str() {
+                "enabled" => Ok(FilterNode::Enabled),
+                "name" => {
+                    if self.eat("~") {
+                        Ok(FilterNode::NameContains(self.text()?.to_lowercase()))
+                    } else if self.eat("==") || self.eat("=") {
+                        Ok(FilterNode::NameIs(self.text()?))
+                    } else {
+                        Err("\"name\" must be followed by \"~\" or \"=\"".to_string())
+                    }
+                }
+                "level" => {
+                    let comparison = self.comparison()?;
+                    let level = self.text()?;
+                    let level = level
+                        .parse()
+                        .map_err(|_| format!("\"{}\" is not a log level", level))?;
+                    Ok(FilterNode::Level(comparison, level))
+                }
+                "channel" | "group" => {
+                    if !(self.eat("==") || self.eat("=")) {
+                        return Err(format!("\"{}\" must be followed by \"=\"", word));
+                    }
+                    let value = self.text()?;
+                    Ok(if word == "channel" {
+                        FilterNode::Channel(value)
+                    } else {
+                        FilterNode::Group(value)
+                    })
+                }
+                _ => Ok(FilterNode::NameContains(word.to_lowercase())),
+            },
+            Some(Token::Symbol(symbol)) => Err(format!("unexpected \"{}\"", symbol)),
+            None => Err("the expression ends too early".to_string()),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, String> {
+        let comparison = match self.next() {
+            Some(Token::Symbol("=" | "==")) => Comparison::Equal,
+            Some(Token::Symbol("!=")) => Comparison::NotEqual,
+            Some(Token::Symbol("<")) => Comparison::Less,
+            Some(Token::Symbol("<=")) => Comparison::LessOrEqual,
+            Some(Token::Symbol(">")) => Comparison::Greater,
+            Some(Token::Symbol(">=")) => Comparison::GreaterOrEqual,
+            _ => return Err("\"level\" must be followed by a comparison".to_string()),
+        };
+        Ok(comparison)
+    }
+
+    fn text(&mut self) -> Result<String, String> {
+        match self.next() {
+            Some(Token::Text(text) | Token::Word(text)) => Ok(text),
+            _ => Err("a value is missing".to_string()),
+        }
+    }
+}
+
+/// Parse a filter expression, returning why it is not valid otherwise.
+pub(crate) fn parse_filter(expression: &str) -> Result<FilterNode, String> {
+    let mut parser = FilterParser {
+        tokens: tokenize(expression)?,
+        position: 0,
+    };
+    let node = parser.or()?;
+    match parser.peek() {
+        None => Ok(node),
+        Some(Token::Symbol(symbol)) => Err(format!("unexpected \"{}\"", symbol)),
+        Some(Token::Word(word) | Token::Text(word)) => Err(format!("unexpected \"{}\"", word)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(expression: &str, name: &str, settings: &EventSettings) -> bool {
+        parse_filter(expression).unwrap().matches(&FilterSubject {
+            name,
+            settings,
+            channel: Some("net"),
+            group: None,
+        })
+    }
+
+    #[derive(Event)]
+    struct Moved;
+
+    #[derive(Resource)]
+    struct Other(u64);
+
+    #[test]
+    fn settings_by_id() {
+        let mut world = World::new();
+        world.insert_resource(LoggedEventSettings::<Moved>::default());
+        let id = SettingsId::of::<Moved, ()>(&world).unwrap();
+        assert!(get_log_settings_by_id(&world, &id).is_some());
+        get_log_settings_mut_by_id(&mut world, &id).unwrap().enabled = false;
+        assert!(!world.resource::<LoggedEventSettings<Moved>>().enabled);
+    }
+
+    #[test]
+    fn settings_by_wrong_id() {
+        let mut world = World::new();
+        world.insert_resource(Other(0));
+        // The id of a resource that is not the one the id was created for.
+        let id = SettingsId {
+            component: world.components().resource_id::<Other>().unwrap(),
+            type_id: TypeId::of::<LoggedEventSettings<Moved>>(),
+        };
+        assert!(get_log_settings_by_id(&world, &id).is_none());
+        assert!(get_log_settings_mut_by_id(&mut world, &id).is_none());
+        assert_eq!(world.resource::<Other>().0, 0);
+        // An id coming from another world.
+        let mut other_world = World::new();
+        other_world.insert_resource(LoggedEventSettings::<Moved>::default());
+        let id = SettingsId::of::<Moved, ()>(&other_world).unwrap();
+        assert!(get_log_settings_by_id(&world, &id).is_none());
+        assert!(get_log_settings_mut_by_id(&mut world, &id).is_none());
+    }
+
+    #[test]
+    fn short_names() {
+        assert_eq!(short_type_name("game::player::Moved"), "Moved");
+        assert_eq!(short_type_name("Moved"), "Moved");
+        assert_eq!(
+            short_type_name("bevy_ecs::event::Events<game::net::Packet<u8>>"),
+            "Events<Packet<u8>>"
+        );
+        assert_eq!(
+            short_type_name("game::Pair<(a::B, &c::d::E), [f::G; 2]>"),
+            "Pair<(B, &E), [G; 2]>"
+        );
+    }
+
+    #[test]
+    fn abbreviated_names() {
+        assert_eq!(abbreviated_type_name("game::player::Moved"), "g::p::Moved");
+        assert_eq!(abbreviated_type_name("Moved"), "Moved");
+        assert_eq!(
+            abbreviated_type_name("bevy_ecs::event::Events<game::net::Packet<u8>>"),
+            "b::e::Events<g::n::Packet<u8>>"
+        );
+        assert_eq!(
+            abbreviated_type_name("game::Pair<(a::B, &c::d::E), [f::G; 2]>"),
+            "g::Pair<(a::B, &c::d::E), [f::G; 2]>"
+        );
+    }
+
+    #[test]
+    fn parse_combined_expression() {
+        let settings = EventSettings {
+            enabled: true,
+            level: Level::DEBUG,
+            ..default()
+        };
+        let expression = "name~\"combat\" && level>=DEBUG && enabled";
+        assert!(matches(expression, "game::CombatEvent", &settings));
+        assert!(!matches(expression, "game::MoveEvent", &settings));
+        assert!(matches("!enabled || channel=net", "a", &settings));
+        assert!(!matches("(enabled && level<DEBUG)", "a", &settings));
+    }
+
+    #[test]
+    fn lone_symbols_are_errors() {
+        assert_eq!(
+            parse_filter("enabled &"),
+            Err("unexpected \"&\"".to_string())
+        );
+        assert_eq!(parse_filter("a | b"), Err("unexpected \"|\"".to_string()));
+        assert!(parse_filter("enabled && level>=DEBUG").is_ok());
+    }
+
+    #[test]
+    fn invalid_expressions_are_errors() {
+        assert!(parse_filter("name~\"combat").is_err());
+        assert!(parse_filter("(enabled").is_err());
+        assert!(parse_filter("level>=LOUD").is_err());
+        assert!(parse_filter("enabled &&").is_err());
+        assert!(parse_filter("name").is_err());
+    }
 }
@@ -0,0 +1,162 @@
+use std::marker::PhantomData;
+
+use bevy::{ecs::component::ComponentId, ecs::reflect::ReflectComponent, prelude::*};
+
+use crate::{
+    new_logged_settings,
+    systems::{
+        append_entity_info, emit, entity_label, format_component, register_settings, LogSettingsIds,
+    },
+    EntityLabeler, LogEventsPluginSettings, LogInstance, LoggedEventSettings, LOG_TARGET,
+    MAX_WATCHED,
+};
+
+/// Marker used for the [LoggedEventSettings] of the `N`-th watched component of the
+/// instance `I`.
+pub(crate) struct Watched<I, const N: usize>(PhantomData<fn() -> I>);
+
+type WatchFn = fn(&mut World, WatchTarget);
+
+macro_rules! watchers {
+    ($($n:literal),*) => {
+        fn watchers<I: LogInstance>() -> [WatchFn; MAX_WATCHED] {
+            [$(watch::<I, $n> as WatchFn),*]
+        }
+    };
+}
+
+// One function per watched slot, so each one gets its own [LoggedEventSettings].
+watchers!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31
+);
+
+struct WatchTarget {
+    name: String,
+    lifecycle: Lifecycle,
+    component: ComponentId,
+    reflect: ReflectComponent,
+}
+
+#[derive(Clone, Copy)]
+enum Lifecycle {
+    Add,
+    Insert,
+    Replace,
+    Remove,
+}
+
+/// Split an entry like `"OnInsert<my_game::Health>"` into its lifecycle event and the
+/// type path of its component.
+fn parse_entry(entry: &str) -> Option<(Lifecycle, &str)> {
+    let (event, rest) = entry.trim().split_once('<')?;
+    let path = rest.strip_suffix('>')?;
+    let lifecycle = match event {
+        "OnAdd" => Lifecycle::Add,
+        "OnInsert" => Lifecycle::Insert,
+        "OnReplace" => Lifecycle::Replace,
+        "OnRemove" => Lifecycle::Remove,
+        _ => return None,
+    };
+    Some((lifecycle, path))
+}
+
+fn find_target(world: &mut World, entry: &str) -> Result<WatchTarget, String> {
+    let (lifecycle, path) = parse_entry(entry)
+        .ok_or("expected an entry like \"OnInsert<my_game::MyComponent>\"".to_string())?;
+    let reflect = {
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .ok_or("there is no AppTypeRegistry".to_string())?
+            .read();
+        let registration = registry
+            .get_with_type_path(path)
+            .ok_or(format!("{} is not registered for reflection", path))?;
+        registration
+            .data::<ReflectComponent>()
+            .ok_or(format!("{} does not reflect Component", path))?
+            .clone()
+    };
+    let component = reflect.register_component(world);
+    let (event, _) = entry.trim().split_once('<').unwrap_or_default();
+    Ok(WatchTarget {
+        name: format!("{}<{}>", event, path),
+        lifecycle,
+        component,
+        reflect,
+    })
+}
+
+/// Register the components listed in [watch](LogEventsPluginSettings::watch).
+pub(crate) fn register_watched<I: LogInstance>(world: &mut World) {
+    let entries = world.resource::<LogEventsPluginSettings<I>>().watch.clone();
+    if entries.len() > MAX_WATCHED {
+        warn!(target: LOG_TARGET, "Only the first {} watched components will be logged", MAX_WATCHED);
+    }
+    for (entry, watcher) in entries.iter().zip(watchers::<I>()) {
+        match find_target(world, entry) {
+            Ok(target)
+                if world
+                    .resource::<LogSettingsIds<I>>()
+                    .contains_key(&target.name) =>
+            {
+                warn!(target: LOG_TARGET, "{} is already logged", target.name);
+            }
+            Ok(target) => watcher(world, target),
+            Err(err) => {
+                warn!(target: LOG_TARGET, "Can not watch {:?}: {}", entry, err);
+            }
+        }
+    }
+}
+
+fn watch<I: LogInstance, const N: usize>(world: &mut World, target: WatchTarget) {
+    let settings = new_logged_settings::<Watched<I, N>, ()>(world);
+    world.insert_resource(settings);
+    register_settings::<Watched<I, N>, (), I>(world, target.name.clone());
+    match target.lifecycle {
+        Lifecycle::Add => spawn_watcher::<OnAdd, I, N>(world, target),
+        Lifecycle::Insert => spawn_watcher::<OnInsert, I, N>(world, target),
+        Lifecycle::Replace => spawn_watcher::<OnReplace, I, N>(world, target),
+        Lifecycle::Remove => spawn_watcher::<OnRemove, I, N>(world, target),
+    }
+}
+
+fn spawn_watcher<E: Event, I: LogInstance, const N: usize>(world: &mut World, target: WatchTarget) {
+    let WatchTarget {
+        name,
+        component,
+        reflect,
+        ..
+    } = target;
+    let observer = Observer::new(
+        move |trigger: Trigger<E>,
+              plugin_settings: Res<LogEventsPluginSettings<I>>,
+              settings: Res<LoggedEventSettings<Watched<I, N>>>,
+              labeler: Res<EntityLabeler>,
+              entities: Query<EntityRef>| {
+            if !plugin_settings.must_log(&settings) {
+                return;
+            }
+            let entity = trigger.entity();
+            if let Some(mut line) = format_component(
+                &plugin_settings,
+                &settings,
+                &name,
+                entity,
+                entity_label(&labeler, &entities, entity),
+                entities
+                    .get(entity)
+                    .ok()
+                    .and_then(|entity| reflect.reflect(entity)),
+            ) {
+                if settings.entity_info {
+                    append_entity_info(&mut line, &entities, entity);
+                }
+                emit(&plugin_settings, &settings, line);
+            }
+        },
+    )
+    .with_component(component);
+    world.spawn(observer);
+}